@@ -0,0 +1,79 @@
+use std::{
+    io::{BufRead, Seek},
+    time::Duration,
+};
+
+use binary_image::BinaryImage;
+use image::{
+    codecs::{gif::GifDecoder, png::PngDecoder},
+    AnimationDecoder, DynamicImage, ImageResult,
+};
+
+use crate::Edges;
+
+/// A frame of an animation with the edges of its objects.
+#[derive(Debug, Clone)]
+pub struct Frame {
+    /// The edges of the whole canvas once the frame is drawn over the previous ones.
+    pub edges: Edges<BinaryImage>,
+    /// How long the frame is shown.
+    pub delay: Duration,
+}
+
+/// Decodes every frame of an animation.
+///
+/// Frames are composited by the decoder, following the disposal method of each frame,
+/// so every frame covers the whole canvas like it is shown.
+///
+/// # Errors
+///
+/// Returns an error if a frame can not be decoded.
+pub fn frames<'a>(decoder: impl AnimationDecoder<'a>) -> ImageResult<Vec<Frame>> {
+    decoder
+        .into_frames()
+        .map(|frame| {
+            frame.map(|frame| Frame {
+                delay: Duration::from(frame.delay()),
+                edges: Edges::from(DynamicImage::ImageRgba8(frame.into_buffer())),
+            })
+        })
+        .collect()
+}
+
+/// Decodes every frame of a GIF, see [`frames`].
+///
+/// # Example
+///
+/// ```no_run
+/// # use std::{fs::File, io::BufReader};
+/// let file = BufReader::new(File::open("walk.gif").unwrap());
+/// for frame in edges::animation::gif(file).unwrap() {
+///     println!("{:?}: {:?}", frame.delay, frame.edges.multi_translated());
+/// }
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if the data is not a GIF or a frame can not be decoded.
+pub fn gif<R: BufRead + Seek>(reader: R) -> ImageResult<Vec<Frame>> {
+    frames(GifDecoder::new(reader)?)
+}
+
+/// Decodes every frame of an APNG, see [`frames`].
+///
+/// A PNG without animation gives a single frame without delay.
+///
+/// # Errors
+///
+/// Returns an error if the data is not a PNG or a frame can not be decoded.
+pub fn apng<R: BufRead + Seek>(reader: R) -> ImageResult<Vec<Frame>> {
+    let decoder = PngDecoder::new(reader)?;
+    if decoder.is_apng()? {
+        frames(decoder.apng()?)
+    } else {
+        Ok(vec![Frame {
+            edges: Edges::from(DynamicImage::from_decoder(decoder)?),
+            delay: Duration::ZERO,
+        }])
+    }
+}
@@ -0,0 +1,72 @@
+use crate::{
+    clip,
+    utils::{group, orient},
+    DVec2, Vec2,
+};
+
+/// How two sets of objects are combined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    /// The area covered by either set.
+    Union,
+    /// The area covered by both sets.
+    Intersection,
+    /// The area covered by the first set but not the second one.
+    Difference,
+    /// The area covered by exactly one of the sets.
+    Xor,
+}
+
+impl Operation {
+    fn inside(self, first: bool, second: bool) -> bool {
+        match self {
+            Self::Union => first || second,
+            Self::Intersection => first && second,
+            Self::Difference => first && !second,
+            Self::Xor => first != second,
+        }
+    }
+}
+
+/// Combines two sets of objects, each made of an outline followed by its holes like the objects of
+/// [`Edges::multi_translated_with_holes`](crate::Edges::multi_translated_with_holes).
+///
+/// Objects overlapping within a set are merged first, so layers can be passed as they are traced.
+///
+/// # Example
+///
+/// ```
+/// # use edges::{boolean::{boolean, Operation}, Edges};
+/// # let body = Edges::from(image::open("assets/car.png").unwrap());
+/// # let damage = Edges::from(image::open("assets/boulders.png").unwrap());
+/// let objects = boolean(
+///     &body.multi_translated_with_holes(),
+///     &damage.multi_translated_with_holes(),
+///     Operation::Difference,
+/// );
+/// ```
+///
+/// # Returns
+///
+/// A vector of objects in the same format: a counter-clockwise outline (when y points up)
+/// followed by the clockwise holes directly inside it, from the largest outline to the smallest.
+#[must_use]
+pub fn boolean(
+    first: &[Vec<Vec<Vec2>>],
+    second: &[Vec<Vec<Vec2>>],
+    operation: Operation,
+) -> Vec<Vec<Vec<Vec2>>> {
+    let (first, second) = (rings(first), rings(second));
+    group(clip::resolve(&[&first, &second], |windings| {
+        operation.inside(windings[0] > 0, windings[1] > 0)
+    }))
+}
+
+/// Orients the rings of every object on its own, so that overlapping objects add up.
+fn rings(objects: &[Vec<Vec<Vec2>>]) -> Vec<Vec<DVec2>> {
+    objects
+        .iter()
+        .flat_map(|object| clip::to_f64(&orient(object.clone())))
+        .filter(|ring| ring.len() > 2)
+        .collect()
+}
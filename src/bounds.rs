@@ -0,0 +1,228 @@
+use crate::{DVec2, Vec2};
+
+/// A rectangle rotated around its center.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct OrientedBox {
+    pub center: Vec2,
+    /// Half of the width and height of the box, along its own axes.
+    pub half_size: Vec2,
+    /// The angle of the width of the box from the x axis, in radians.
+    pub rotation: f32,
+}
+
+impl OrientedBox {
+    /// The corners of the box, counter-clockwise when y points up.
+    #[must_use]
+    pub fn corners(&self) -> [Vec2; 4] {
+        let (sin, cos) = self.rotation.sin_cos();
+        let (x, y) = (
+            Vec2::new(cos, sin) * self.half_size.x,
+            Vec2::new(-sin, cos) * self.half_size.y,
+        );
+        [
+            self.center - x - y,
+            self.center + x - y,
+            self.center + x + y,
+            self.center - x + y,
+        ]
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn area(&self) -> f32 {
+        4. * self.half_size.x * self.half_size.y
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Circle {
+    pub center: Vec2,
+    pub radius: f32,
+}
+
+/// Computes the convex hull of points with Andrew's monotone chain, leaving out points
+/// in the middle of its edges.
+///
+/// # Returns
+///
+/// The corners of the hull counter-clockwise (when y points up), starting from the leftmost one.
+#[must_use]
+pub fn convex_hull(points: &[Vec2]) -> Vec<Vec2> {
+    let mut points = points.to_vec();
+    points.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+    points.dedup();
+    if points.len() < 3 {
+        return points;
+    }
+
+    let cross = |o: Vec2, a: Vec2, b: Vec2| (a - o).perp_dot(b - o);
+    let mut hull: Vec<Vec2> = Vec::with_capacity(points.len() + 1);
+    for pass in [
+        points.as_slice(),
+        &points.iter().rev().copied().collect::<Vec<_>>(),
+    ] {
+        let start = hull.len();
+        for &p in pass {
+            while hull.len() >= start + 2
+                && cross(hull[hull.len() - 2], hull[hull.len() - 1], p) <= 0.
+            {
+                hull.pop();
+            }
+            hull.push(p);
+        }
+        // The last point of each chain starts the other one.
+        hull.pop();
+    }
+    hull
+}
+
+/// Finds the rectangle of minimum area containing points with rotating calipers over their
+/// convex hull, one side of the rectangle lying on an edge of the hull.
+///
+/// # Example
+///
+/// ```
+/// # use edges::{bounds::oriented_bounding_box, Edges};
+/// # let image = image::open("assets/car.png").unwrap();
+/// let edges = Edges::from(image);
+/// let car = oriented_bounding_box(&edges.single_translated().unwrap()).unwrap();
+/// let corners = car.corners();
+/// ```
+///
+/// # Returns
+///
+/// The box, or `None` if there are no points.
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub fn oriented_bounding_box(points: &[Vec2]) -> Option<OrientedBox> {
+    let hull: Vec<DVec2> = convex_hull(points).iter().map(Vec2::as_dvec2).collect();
+    let len = hull.len();
+    match len {
+        0 => return None,
+        1 => {
+            return Some(OrientedBox {
+                center: hull[0].as_vec2(),
+                ..OrientedBox::default()
+            })
+        }
+        _ => {}
+    }
+
+    let dot = |i: usize, origin: DVec2, axis: DVec2| (hull[i % len] - origin).dot(axis);
+    let (mut right, mut top, mut left) = (0, 0, 0);
+    let mut best: Option<(f64, OrientedBox)> = None;
+    for i in 0..len {
+        let origin = hull[i];
+        let along = (hull[(i + 1) % len] - origin).normalize();
+        let normal = along.perp();
+        // Each caliper only moves forward around the hull as the edges turn.
+        right = right.max(i);
+        while dot(right + 1, origin, along) >= dot(right, origin, along) && right < i + len {
+            right += 1;
+        }
+        top = top.max(right);
+        while dot(top + 1, origin, normal) >= dot(top, origin, normal) && top < i + len {
+            top += 1;
+        }
+        left = left.max(top);
+        while dot(left + 1, origin, along) <= dot(left, origin, along) && left < i + len {
+            left += 1;
+        }
+
+        let (min, max) = (dot(left, origin, along), dot(right, origin, along));
+        let height = dot(top, origin, normal);
+        let area = (max - min) * height;
+        if best.as_ref().is_none_or(|(best, _)| area < *best) {
+            let center = origin + along * (min + max) / 2. + normal * height / 2.;
+            best = Some((
+                area,
+                OrientedBox {
+                    center: center.as_vec2(),
+                    half_size: DVec2::new((max - min) / 2., height / 2.).as_vec2(),
+                    rotation: along.y.atan2(along.x) as f32,
+                },
+            ));
+        }
+    }
+    best.map(|(_, rectangle)| rectangle)
+}
+
+/// Finds the smallest circle containing points with Welzl's algorithm over their convex hull.
+///
+/// # Example
+///
+/// ```
+/// # use edges::{bounds::enclosing_circle, Edges};
+/// # let image = image::open("assets/boulders.png").unwrap();
+/// let edges = Edges::from(image);
+/// let circles: Vec<_> = edges
+///     .multi_translated()
+///     .iter()
+///     .filter_map(|polygon| enclosing_circle(polygon))
+///     .collect();
+/// ```
+///
+/// # Returns
+///
+/// The circle, or `None` if there are no points.
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub fn enclosing_circle(points: &[Vec2]) -> Option<Circle> {
+    let mut hull: Vec<DVec2> = convex_hull(points).iter().map(Vec2::as_dvec2).collect();
+    // Welzl's algorithm runs in expected linear time on points in random order, shuffled here
+    // with a fixed seed so results are reproducible.
+    let mut seed: u64 = 0x2545_f491_4f6c_dd1d;
+    for i in (1..hull.len()).rev() {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        hull.swap(i, (seed % (i as u64 + 1)) as usize);
+    }
+
+    let outside =
+        |(center, radius): (DVec2, f64), p: DVec2| center.distance(p) > radius * (1. + 1e-9) + 1e-9;
+    let mut circle = (*hull.first()?, 0.);
+    for i in 1..hull.len() {
+        if !outside(circle, hull[i]) {
+            continue;
+        }
+        circle = (hull[i], 0.);
+        for j in 0..i {
+            if !outside(circle, hull[j]) {
+                continue;
+            }
+            circle = diameter(hull[i], hull[j]);
+            for k in 0..j {
+                if outside(circle, hull[k]) {
+                    circle = circumcircle(hull[i], hull[j], hull[k]);
+                }
+            }
+        }
+    }
+    Some(Circle {
+        center: circle.0.as_vec2(),
+        radius: circle.1 as f32,
+    })
+}
+
+/// The circle whose diameter is the segment `ab`.
+fn diameter(a: DVec2, b: DVec2) -> (DVec2, f64) {
+    (a.midpoint(b), a.distance(b) / 2.)
+}
+
+/// The circle through three points, or the largest circle through two of them when they are aligned.
+fn circumcircle(a: DVec2, b: DVec2, c: DVec2) -> (DVec2, f64) {
+    let (ab, ac) = (b - a, c - a);
+    let d = 2. * ab.perp_dot(ac);
+    if d.abs() < 1e-12 {
+        return [diameter(a, b), diameter(a, c), diameter(b, c)]
+            .into_iter()
+            .max_by(|x, y| x.1.total_cmp(&y.1))
+            .unwrap_or((a, 0.));
+    }
+    let center = a + DVec2::new(
+        ac.y * ab.length_squared() - ab.y * ac.length_squared(),
+        ab.x * ac.length_squared() - ac.x * ab.length_squared(),
+    ) / d;
+    (center, center.distance(a))
+}
@@ -0,0 +1,318 @@
+//! Resolving overlapping rings into simple polygons.
+//!
+//! Every edge is split wherever it meets another one, the winding numbers on both
+//! sides of each piece decide whether it lies on the boundary of the result,
+//! and the boundary pieces are linked back into rings with the inside on their left.
+
+use std::collections::{BTreeMap, HashMap};
+
+use crate::{utils::signed_area, DVec2, Vec2};
+
+/// Quantization applied to vertices so that the same intersection computed from
+/// different edges ends up as a single vertex.
+const SNAP: f64 = 1e-6;
+
+#[derive(Clone, Copy)]
+struct Edge {
+    a: DVec2,
+    b: DVec2,
+    set: usize,
+}
+
+/// Resolves the rings of `sets` into simple rings bounding the area where `inside` holds.
+///
+/// `inside` receives the winding number of every set around a point.
+/// Returned rings have the inside on their left: outlines have a positive signed area, holes a negative one.
+/// Outlines come first, from the largest to the smallest, followed by the holes.
+pub(crate) fn resolve<F>(sets: &[&[Vec<DVec2>]], inside: F) -> Vec<Vec<Vec2>>
+where
+    F: Fn(&[i32]) -> bool,
+{
+    let edges: Vec<Edge> = sets
+        .iter()
+        .enumerate()
+        .flat_map(|(set, rings)| {
+            rings.iter().flat_map(move |ring| {
+                (0..ring.len()).filter_map(move |i| {
+                    let (a, b) = (ring[i], ring[(i + 1) % ring.len()]);
+                    (a != b).then_some(Edge { a, b, set })
+                })
+            })
+        })
+        .collect();
+    if edges.is_empty() {
+        return Vec::new();
+    }
+
+    let index = RowIndex::new(&edges);
+    let mut vertices = Vertices::default();
+    let mut pieces = BTreeMap::new();
+    for (edge, splits) in edges.iter().zip(split_points(&edges)) {
+        let ids: Vec<usize> = splits
+            .into_iter()
+            .map(|t| vertices.insert(edge.a.lerp(edge.b, t)))
+            .collect();
+        for pair in ids.windows(2) {
+            if pair[0] != pair[1] {
+                pieces
+                    .entry((pair[0].min(pair[1]), pair[0].max(pair[1])))
+                    .or_insert((pair[0], pair[1]));
+            }
+        }
+    }
+
+    let mut windings = vec![0; sets.len()];
+    let mut boundary = Vec::new();
+    for (a, b) in pieces.into_values() {
+        let (pa, pb) = (vertices.points[a], vertices.points[b]);
+        let direction = pb - pa;
+        let length = direction.length();
+        let normal = direction.perp() / length * (length * 0.25).min(1e-5);
+        let middle = pa.lerp(pb, 0.5);
+
+        index.windings(&edges, middle + normal, &mut windings);
+        let left = inside(&windings);
+        index.windings(&edges, middle - normal, &mut windings);
+        let right = inside(&windings);
+        match (left, right) {
+            (true, false) => boundary.push((a, b)),
+            (false, true) => boundary.push((b, a)),
+            _ => {}
+        }
+    }
+
+    let mut rings = link(&vertices.points, &boundary);
+    rings.sort_by(|a, b| signed_area(b).total_cmp(&signed_area(a)));
+    rings
+}
+
+/// Parameters along each edge where it starts, ends or meets another edge, in increasing order.
+fn split_points(edges: &[Edge]) -> Vec<Vec<f64>> {
+    let mut splits: Vec<Vec<f64>> = vec![vec![0., 1.]; edges.len()];
+    let mut order: Vec<usize> = (0..edges.len()).collect();
+    order.sort_by(|&i, &j| {
+        let (a, b) = (&edges[i], &edges[j]);
+        a.a.x.min(a.b.x).total_cmp(&b.a.x.min(b.b.x))
+    });
+
+    for (n, &i) in order.iter().enumerate() {
+        let e = edges[i];
+        let (min, max) = (e.a.min(e.b), e.a.max(e.b));
+        for &j in &order[n + 1..] {
+            let f = edges[j];
+            let (fmin, fmax) = (f.a.min(f.b), f.a.max(f.b));
+            if fmin.x > max.x {
+                break;
+            }
+            if fmin.y > max.y || fmax.y < min.y {
+                continue;
+            }
+            let (s, t) = intersect(e, f);
+            splits[i].extend(s);
+            splits[j].extend(t);
+        }
+    }
+
+    for split in &mut splits {
+        split.retain(|t| (0.0..=1.0).contains(t));
+        split.sort_by(f64::total_cmp);
+        split.dedup();
+    }
+    splits
+}
+
+/// Parameters at which `first` and `second` meet, along `first` and along `second`.
+fn intersect(first: Edge, second: Edge) -> (Vec<f64>, Vec<f64>) {
+    let u = first.b - first.a;
+    let v = second.b - second.a;
+    let denominator = u.perp_dot(v);
+    let offset = second.a - first.a;
+
+    if denominator.abs() > 1e-12 * u.length() * v.length() {
+        let along_first = offset.perp_dot(v) / denominator;
+        let along_second = offset.perp_dot(u) / denominator;
+        if (0.0..=1.0).contains(&along_first) && (0.0..=1.0).contains(&along_second) {
+            return (vec![along_first], vec![along_second]);
+        }
+        return (Vec::new(), Vec::new());
+    }
+
+    // Parallel edges only meet when collinear, where each is split at the other's ends.
+    if offset.perp_dot(u).abs() > 1e-9 * u.length() * u.length().max(offset.length()) {
+        return (Vec::new(), Vec::new());
+    }
+    let along_first = |p: DVec2| (p - first.a).dot(u) / u.length_squared();
+    let along_second = |p: DVec2| (p - second.a).dot(v) / v.length_squared();
+    (
+        vec![along_first(second.a), along_first(second.b)],
+        vec![along_second(first.a), along_second(first.b)],
+    )
+}
+
+#[derive(Default)]
+struct Vertices {
+    points: Vec<DVec2>,
+    ids: HashMap<(i64, i64), usize>,
+}
+
+impl Vertices {
+    #[allow(clippy::cast_possible_truncation)]
+    fn insert(&mut self, point: DVec2) -> usize {
+        let key = (
+            (point.x / SNAP).round() as i64,
+            (point.y / SNAP).round() as i64,
+        );
+        *self.ids.entry(key).or_insert_with(|| {
+            self.points.push(point);
+            self.points.len() - 1
+        })
+    }
+}
+
+/// Edges bucketed by the horizontal bands they cross, to count windings without visiting every edge.
+struct RowIndex {
+    min: f64,
+    height: f64,
+    rows: Vec<Vec<usize>>,
+}
+
+impl RowIndex {
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss
+    )]
+    fn new(edges: &[Edge]) -> Self {
+        let (min, max) = edges.iter().fold((f64::MAX, f64::MIN), |(min, max), e| {
+            (min.min(e.a.y).min(e.b.y), max.max(e.a.y).max(e.b.y))
+        });
+        let count = edges.len().isqrt().max(1);
+        let height = ((max - min) / count as f64).max(f64::MIN_POSITIVE);
+        let mut rows = vec![Vec::new(); count];
+        for (i, e) in edges.iter().enumerate() {
+            let first = ((e.a.y.min(e.b.y) - min) / height) as usize;
+            let last = ((e.a.y.max(e.b.y) - min) / height) as usize;
+            for row in &mut rows[first.min(count - 1)..=last.min(count - 1)] {
+                row.push(i);
+            }
+        }
+        Self { min, height, rows }
+    }
+
+    /// Winding numbers of every set around `point`.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn windings(&self, edges: &[Edge], point: DVec2, windings: &mut [i32]) {
+        windings.fill(0);
+        let row = ((point.y - self.min) / self.height).max(0.) as usize;
+        let Some(row) = self.rows.get(row) else {
+            return;
+        };
+        for e in row.iter().map(|&i| &edges[i]) {
+            if e.a.y <= point.y {
+                if e.b.y > point.y && (e.b - e.a).perp_dot(point - e.a) > 0. {
+                    windings[e.set] += 1;
+                }
+            } else if e.b.y <= point.y && (e.b - e.a).perp_dot(point - e.a) < 0. {
+                windings[e.set] -= 1;
+            }
+        }
+    }
+}
+
+/// Links directed boundary pieces into rings, turning as far left as possible at shared vertices
+/// so that rings touching at a vertex stay separate.
+///
+/// Pieces which do not lead back to where they started are dropped rather than returned as open rings.
+#[allow(clippy::cast_possible_truncation)]
+pub(crate) fn link(points: &[DVec2], pieces: &[(usize, usize)]) -> Vec<Vec<Vec2>> {
+    let mut outgoing: HashMap<usize, Vec<usize>> = HashMap::new();
+    for (i, &(a, _)) in pieces.iter().enumerate() {
+        outgoing.entry(a).or_default().push(i);
+    }
+    let mut used = vec![false; pieces.len()];
+    let mut rings = Vec::new();
+
+    for first in 0..pieces.len() {
+        if used[first] {
+            continue;
+        }
+        used[first] = true;
+        let mut ring = vec![pieces[first].0];
+        let mut current = first;
+        let closed = loop {
+            let (a, b) = pieces[current];
+            if b == ring[0] {
+                break true;
+            }
+            let incoming = points[b] - points[a];
+            let next = outgoing.get(&b).and_then(|candidates| {
+                candidates
+                    .iter()
+                    .copied()
+                    .filter(|&i| !used[i])
+                    .max_by(|&i, &j| {
+                        let turn = |i: usize| {
+                            let out = points[pieces[i].1] - points[b];
+                            incoming.perp_dot(out).atan2(incoming.dot(out))
+                        };
+                        turn(i).total_cmp(&turn(j))
+                    })
+            });
+            let Some(next) = next else {
+                break false;
+            };
+            used[next] = true;
+            ring.push(b);
+            current = next;
+        };
+        if !closed {
+            continue;
+        }
+
+        let ring = simplify(ring.into_iter().map(|i| points[i]).collect());
+        if ring.len() >= 3 {
+            rings.push(
+                ring.into_iter()
+                    .map(|p| Vec2::new(p.x as f32, p.y as f32))
+                    .collect(),
+            );
+        }
+    }
+    rings
+}
+
+/// Removes the vertices lying on a straight line between their neighbors.
+fn simplify(mut ring: Vec<DVec2>) -> Vec<DVec2> {
+    let mut changed = true;
+    while changed && ring.len() >= 3 {
+        changed = false;
+        let mut i = 0;
+        while i < ring.len() && ring.len() >= 3 {
+            let previous = ring[(i + ring.len() - 1) % ring.len()];
+            let next = ring[(i + 1) % ring.len()];
+            let (u, v) = (ring[i] - previous, next - ring[i]);
+            if u.perp_dot(v).abs() <= 1e-9 * u.length() * v.length() && u.dot(v) > 0. {
+                ring.remove(i);
+                changed = true;
+            } else {
+                i += 1;
+            }
+        }
+    }
+    ring
+}
+
+/// Converts rings to double precision, dropping repeated vertices.
+pub(crate) fn to_f64(polygons: &[Vec<Vec2>]) -> Vec<Vec<DVec2>> {
+    polygons
+        .iter()
+        .map(|polygon| {
+            let mut ring: Vec<DVec2> = polygon.iter().map(Vec2::as_dvec2).collect();
+            ring.dedup();
+            while ring.len() > 1 && ring.first() == ring.last() {
+                ring.pop();
+            }
+            ring
+        })
+        .collect()
+}
@@ -0,0 +1,219 @@
+use binary_image::Bit;
+use image::GenericImageView;
+
+use crate::{triangulate::triangulate, Edges, Vec2};
+
+/// How colliders are shaped from the edges of an image.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Strategy {
+    /// Closed polylines along the outlines and holes, hollow and cheap.
+    #[default]
+    Polyline,
+    /// Triangles covering the objects, solid and exact.
+    Trimesh,
+    /// The convex hull of each object.
+    ConvexHull,
+    /// Convex parts approximating each object, solid and fast to query.
+    ConvexDecomposition,
+}
+
+/// The geometry of a collider, in meters.
+///
+/// Polylines, triangle meshes and decompositions are composite shapes which cannot be nested
+/// in compounds, so they hold every object at once while hulls are made per object.
+pub(crate) enum Part {
+    Segments(Vec<Vec2>, Vec<[u32; 2]>),
+    Triangles(Vec<Vec2>, Vec<[u32; 3]>),
+    Hull(Vec<Vec2>),
+    Decomposition(Vec<Vec2>, Vec<[u32; 2]>),
+}
+
+/// Shapes the translated objects, anchored at the center of the image like a sprite,
+/// with `pixels_per_meter` pixels to a unit of the physics engine.
+#[allow(clippy::cast_possible_truncation)]
+pub(crate) fn parts<I>(edges: &Edges<I>, strategy: Strategy, pixels_per_meter: f32) -> Vec<Part>
+where
+    I: GenericImageView<Pixel = Bit>,
+{
+    let scale = 1. / pixels_per_meter.max(f32::EPSILON);
+    let objects = edges.multi_translated_with_holes();
+    let segments = || {
+        let mut vertices = Vec::new();
+        let mut indices = Vec::new();
+        for ring in objects.iter().flatten() {
+            let mut ring = ring.clone();
+            ring.dedup();
+            if ring.len() > 1 && ring.first() == ring.last() {
+                ring.pop();
+            }
+            let first = vertices.len() as u32;
+            let len = ring.len() as u32;
+            if len > 1 {
+                indices.extend((0..len).map(|i| [first + i, first + (i + 1) % len]));
+            }
+            vertices.extend(ring.into_iter().map(|p| p * scale));
+        }
+        (vertices, indices)
+    };
+
+    match strategy {
+        Strategy::Polyline => {
+            let (vertices, indices) = segments();
+            (!indices.is_empty())
+                .then_some(Part::Segments(vertices, indices))
+                .into_iter()
+                .collect()
+        }
+        Strategy::Trimesh => {
+            let mut vertices = Vec::new();
+            let mut indices = Vec::new();
+            for object in &objects {
+                let triangles = triangulate(object);
+                let first = vertices.len() as u32;
+                vertices.extend(triangles.vertices.iter().map(|&p| p * scale));
+                indices.extend(
+                    triangles
+                        .indices
+                        .iter()
+                        .map(|triangle| triangle.map(|i| first + i as u32)),
+                );
+            }
+            (!indices.is_empty())
+                .then_some(Part::Triangles(vertices, indices))
+                .into_iter()
+                .collect()
+        }
+        Strategy::ConvexHull => objects
+            .iter()
+            .filter_map(|object| object.first())
+            .filter(|outline| outline.len() > 2)
+            .map(|outline| Part::Hull(outline.iter().map(|&p| p * scale).collect()))
+            .collect(),
+        Strategy::ConvexDecomposition => {
+            let (vertices, indices) = segments();
+            (indices.len() > 2)
+                .then_some(Part::Decomposition(vertices, indices))
+                .into_iter()
+                .collect()
+        }
+    }
+}
+
+#[cfg(feature = "rapier2d")]
+mod rapier {
+    use binary_image::Bit;
+    use image::GenericImageView;
+    use rapier2d::{
+        geometry::{ColliderBuilder, SharedShape},
+        math::{Isometry, Point},
+    };
+
+    use super::{parts, Part, Strategy};
+    use crate::{Edges, Vec2};
+
+    fn points(vertices: &[Vec2]) -> Vec<Point<f32>> {
+        vertices.iter().map(|p| Point::new(p.x, p.y)).collect()
+    }
+
+    impl<I> Edges<I>
+    where
+        I: GenericImageView<Pixel = Bit>,
+    {
+        /// Builds a Rapier collider from the objects of the image, see [`Strategy`] for the shapes.
+        ///
+        /// Points are anchored at the center of the image and divided by `pixels_per_meter`.
+        ///
+        /// # Returns
+        ///
+        /// A collider holding every object, a compound of hulls for [`Strategy::ConvexHull`],
+        /// or `None` if no object has a shape.
+        #[must_use]
+        pub fn rapier_collider(
+            &self,
+            strategy: Strategy,
+            pixels_per_meter: f32,
+        ) -> Option<ColliderBuilder> {
+            let mut shapes: Vec<SharedShape> = parts(self, strategy, pixels_per_meter)
+                .into_iter()
+                .filter_map(|part| match part {
+                    Part::Segments(vertices, indices) => {
+                        Some(SharedShape::polyline(points(&vertices), Some(indices)))
+                    }
+                    Part::Triangles(vertices, indices) => {
+                        SharedShape::trimesh(points(&vertices), indices).ok()
+                    }
+                    Part::Hull(vertices) => SharedShape::convex_hull(&points(&vertices)),
+                    Part::Decomposition(vertices, indices) => Some(
+                        SharedShape::convex_decomposition(&points(&vertices), &indices),
+                    ),
+                })
+                .collect();
+            match shapes.len() {
+                0 => None,
+                1 => shapes.pop().map(ColliderBuilder::new),
+                _ => Some(ColliderBuilder::compound(
+                    shapes
+                        .into_iter()
+                        .map(|shape| (Isometry::identity(), shape))
+                        .collect(),
+                )),
+            }
+        }
+    }
+}
+
+#[cfg(feature = "avian2d")]
+mod avian {
+    use avian2d::prelude::Collider;
+    use binary_image::Bit;
+    use image::GenericImageView;
+
+    use super::{parts, Part, Strategy};
+    use crate::{Edges, Vec2};
+
+    impl<I> Edges<I>
+    where
+        I: GenericImageView<Pixel = Bit>,
+    {
+        /// Builds an Avian collider from the objects of the image, see [`Strategy`] for the shapes.
+        ///
+        /// Points are anchored at the center of the image and divided by `pixels_per_meter`.
+        ///
+        /// # Returns
+        ///
+        /// A collider holding every object, a compound of hulls for [`Strategy::ConvexHull`],
+        /// or `None` if no object has a shape.
+        #[must_use]
+        pub fn avian_collider(
+            &self,
+            strategy: Strategy,
+            pixels_per_meter: f32,
+        ) -> Option<Collider> {
+            let mut colliders: Vec<Collider> = parts(self, strategy, pixels_per_meter)
+                .into_iter()
+                .filter_map(|part| match part {
+                    Part::Segments(vertices, indices) => {
+                        Some(Collider::polyline(vertices, Some(indices)))
+                    }
+                    Part::Triangles(vertices, indices) => {
+                        Some(Collider::trimesh(vertices, indices))
+                    }
+                    Part::Hull(vertices) => Collider::convex_hull(vertices),
+                    Part::Decomposition(vertices, indices) => {
+                        Some(Collider::convex_decomposition(vertices, indices))
+                    }
+                })
+                .collect();
+            match colliders.len() {
+                0 => None,
+                1 => colliders.pop(),
+                _ => Some(Collider::compound(
+                    colliders
+                        .into_iter()
+                        .map(|collider| (Vec2::ZERO, 0., collider))
+                        .collect(),
+                )),
+            }
+        }
+    }
+}
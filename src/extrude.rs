@@ -0,0 +1,143 @@
+use std::{collections::HashSet, fmt::Write};
+
+use crate::{triangulate::triangulate, Vec2, Vec3};
+
+/// A closed triangle mesh made by extruding objects along z.
+///
+/// Every vertex has its own normal and texture coordinates, so corners between the caps and the walls
+/// are sharp.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Extrusion {
+    pub positions: Vec<Vec3>,
+    pub normals: Vec<Vec3>,
+    /// Texture coordinates mapping the caps onto the image, with v pointing down.
+    pub uvs: Vec<Vec2>,
+    /// Indices into the vertices, wound counter-clockwise when looking at the outside of the mesh.
+    pub indices: Vec<[u32; 3]>,
+}
+
+/// Extrudes objects made of an outline followed by their holes, as returned by
+/// [`Edges::multi_translated_with_holes`](crate::Edges::multi_translated_with_holes),
+/// into a closed mesh `depth` thick centered on z = 0.
+///
+/// The front cap faces +z, the back cap faces -z and walls join them along the outlines and holes.
+/// Texture coordinates place the caps on an image of `size` centered at the origin,
+/// like translated points, so the sprite shows on both sides. Walls repeat the pixels of their edges.
+///
+/// # Example
+///
+/// ```
+/// # use edges::{extrude::extrude, Edges};
+/// # let image = image::open("assets/car.png").unwrap();
+/// let edges = Edges::from(image);
+/// let size = [edges.width() as f32, edges.height() as f32];
+/// let mesh = extrude(&edges.multi_translated_with_holes(), 8., size.into());
+/// let obj = mesh.to_obj();
+/// ```
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub fn extrude(objects: &[Vec<Vec<Vec2>>], depth: f32, size: Vec2) -> Extrusion {
+    let half = depth / 2.;
+    let uv = |p: Vec2| Vec2::new(p.x / size.x + 0.5, 0.5 - p.y / size.y);
+    let mut mesh = Extrusion::default();
+    for object in objects {
+        let triangles = triangulate(object);
+        let vertices = &triangles.vertices;
+
+        for (z, normal) in [(half, Vec3::Z), (-half, Vec3::NEG_Z)] {
+            let first = mesh.positions.len() as u32;
+            for &p in vertices {
+                mesh.positions.push(p.extend(z));
+                mesh.normals.push(normal);
+                mesh.uvs.push(uv(p));
+            }
+            mesh.indices
+                .extend(triangles.indices.iter().map(|&[a, b, c]| {
+                    let [a, b, c] = [a, b, c].map(|i| first + i as u32);
+                    if z > 0. {
+                        [a, b, c]
+                    } else {
+                        [a, c, b]
+                    }
+                }));
+        }
+
+        // Edges used by a single triangle are on the boundary, with the inside on their left.
+        let edges = || {
+            triangles
+                .indices
+                .iter()
+                .flat_map(|&[a, b, c]| [(a, b), (b, c), (c, a)])
+        };
+        let inner: HashSet<(usize, usize)> = edges().collect();
+        for (a, b) in edges() {
+            if inner.contains(&(b, a)) {
+                continue;
+            }
+            let (start, end) = (vertices[a], vertices[b]);
+            let direction = (end - start).normalize_or_zero();
+            let normal = Vec3::new(direction.y, -direction.x, 0.);
+            let first = mesh.positions.len() as u32;
+            for (p, z) in [(start, half), (start, -half), (end, -half), (end, half)] {
+                mesh.positions.push(p.extend(z));
+                mesh.normals.push(normal);
+                mesh.uvs.push(uv(p));
+            }
+            mesh.indices
+                .extend([[first, first + 1, first + 2], [first, first + 2, first + 3]]);
+        }
+    }
+    mesh
+}
+
+impl Extrusion {
+    /// Writes the mesh as a Wavefront OBJ file, with texture coordinates flipped to point up
+    /// like OBJ expects.
+    #[must_use]
+    pub fn to_obj(&self) -> String {
+        let mut obj = String::new();
+        for p in &self.positions {
+            _ = writeln!(obj, "v {} {} {}", p.x, p.y, p.z);
+        }
+        for uv in &self.uvs {
+            _ = writeln!(obj, "vt {} {}", uv.x, 1. - uv.y);
+        }
+        for n in &self.normals {
+            _ = writeln!(obj, "vn {} {} {}", n.x, n.y, n.z);
+        }
+        for triangle in &self.indices {
+            let [a, b, c] = triangle.map(|i| i + 1);
+            _ = writeln!(obj, "f {a}/{a}/{a} {b}/{b}/{b} {c}/{c}/{c}");
+        }
+        obj
+    }
+
+    /// Converts the mesh to a Bevy mesh with positions, normals, texture coordinates and indices.
+    #[cfg(feature = "bevy-assets")]
+    #[must_use]
+    pub fn to_bevy_mesh(&self) -> bevy_mesh::Mesh {
+        use bevy_asset::RenderAssetUsages;
+        use bevy_mesh::{Indices, Mesh, PrimitiveTopology};
+
+        Mesh::new(
+            PrimitiveTopology::TriangleList,
+            RenderAssetUsages::default(),
+        )
+        .with_inserted_attribute(
+            Mesh::ATTRIBUTE_POSITION,
+            self.positions
+                .iter()
+                .map(Vec3::to_array)
+                .collect::<Vec<_>>(),
+        )
+        .with_inserted_attribute(
+            Mesh::ATTRIBUTE_NORMAL,
+            self.normals.iter().map(Vec3::to_array).collect::<Vec<_>>(),
+        )
+        .with_inserted_attribute(
+            Mesh::ATTRIBUTE_UV_0,
+            self.uvs.iter().map(Vec2::to_array).collect::<Vec<_>>(),
+        )
+        .with_inserted_indices(Indices::U32(self.indices.concat()))
+    }
+}
@@ -0,0 +1,281 @@
+use std::{collections::HashMap, fmt::Write};
+
+use binary_image::Bit;
+use image::GenericImageView;
+
+use crate::{iter::label::Labels, transform::Transform, DAffine2, DVec2, Edges, UVec2};
+
+/// Exports objects with their holes as `GeoJSON` or WKT polygons.
+///
+/// Like `gdal_polygonize` with 8-connectedness, rings follow the edges of the pixels rather than
+/// their centers, so polygons cover every pixel of their object entirely.
+/// Points are in pixels from the top left corner of the image with y pointing down, so that
+/// the top left corner of pixel (x, y) is at (x, y), and go through an optional affine geo-transform
+/// to world coordinates.
+/// Rings are closed and wound like `GeoJSON` expects: outlines counter-clockwise and holes clockwise,
+/// once transformed. Pixels touching only through a corner belong to the same object,
+/// so its rings touch themselves at that corner.
+///
+/// # Example
+///
+/// ```
+/// # use edges::{geo::Geo, Edges};
+/// # let image = image::open("assets/terrain.png").unwrap();
+/// let edges = Edges::from(image);
+/// // 10 meter pixels from the north west corner of a map.
+/// let geo = Geo::new().gdal([500_000., 10., 0., 4_600_000., 0., -10.]);
+/// let geojson = geo.to_geojson(&edges);
+/// let wkt = geo.to_wkt(&edges);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Geo {
+    transform: Transform,
+}
+
+impl Geo {
+    /// Creates an exporter writing pixel coordinates.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the transform from pixel coordinates to world coordinates.
+    #[inline]
+    #[must_use]
+    pub fn transform(mut self, transform: impl Into<Transform>) -> Self {
+        self.transform = transform.into();
+        self
+    }
+
+    /// Sets the transform from the six coefficients of a GDAL geo-transform:
+    /// `x = c[0] + column * c[1] + row * c[2]` and `y = c[3] + column * c[4] + row * c[5]`.
+    #[inline]
+    #[must_use]
+    pub fn gdal(self, coefficients: [f64; 6]) -> Self {
+        let [x, column_x, row_x, y, column_y, row_y] = coefficients;
+        self.transform(DAffine2::from_cols_array(&[
+            column_x, column_y, row_x, row_y, x, y,
+        ]))
+    }
+
+    /// Transforms the objects of the image to world coordinates.
+    ///
+    /// # Returns
+    ///
+    /// A vector with, for each object, its closed outline followed by its closed holes,
+    /// in the order of the top left pixel of each object.
+    #[must_use]
+    pub fn polygons<I>(&self, edges: &Edges<I>) -> Vec<Vec<Vec<DVec2>>>
+    where
+        I: GenericImageView<Pixel = Bit>,
+    {
+        let affine = self.transform.affine();
+        pixel_rings(&edges.0)
+            .into_iter()
+            .map(|object| {
+                object
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, ring)| {
+                        let mut ring: Vec<DVec2> = ring
+                            .into_iter()
+                            .map(|p| affine.transform_point2(p.as_dvec2()))
+                            .collect();
+                        if (signed_area(&ring) < 0.) == (i == 0) {
+                            ring.reverse();
+                        }
+                        ring.push(ring[0]);
+                        ring
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Writes the objects as a `GeoJSON` geometry, a `Polygon` for a single object
+    /// and a `MultiPolygon` otherwise.
+    #[must_use]
+    pub fn to_geojson<I>(&self, edges: &Edges<I>) -> String
+    where
+        I: GenericImageView<Pixel = Bit>,
+    {
+        let polygon = |json: &mut String, object: &[Vec<DVec2>]| {
+            json.push('[');
+            for (i, ring) in object.iter().enumerate() {
+                if i > 0 {
+                    json.push(',');
+                }
+                json.push('[');
+                for (j, p) in ring.iter().enumerate() {
+                    if j > 0 {
+                        json.push(',');
+                    }
+                    _ = write!(json, "[{},{}]", p.x, p.y);
+                }
+                json.push(']');
+            }
+            json.push(']');
+        };
+
+        let objects = self.polygons(edges);
+        let mut json = String::new();
+        if let [object] = objects.as_slice() {
+            json.push_str(r#"{"type":"Polygon","coordinates":"#);
+            polygon(&mut json, object);
+        } else {
+            json.push_str(r#"{"type":"MultiPolygon","coordinates":["#);
+            for (i, object) in objects.iter().enumerate() {
+                if i > 0 {
+                    json.push(',');
+                }
+                polygon(&mut json, object);
+            }
+            json.push(']');
+        }
+        json.push('}');
+        json
+    }
+
+    /// Writes the objects as Well-Known Text, a `POLYGON` for a single object
+    /// and a `MULTIPOLYGON` otherwise.
+    #[must_use]
+    pub fn to_wkt<I>(&self, edges: &Edges<I>) -> String
+    where
+        I: GenericImageView<Pixel = Bit>,
+    {
+        let polygon = |wkt: &mut String, object: &[Vec<DVec2>]| {
+            wkt.push('(');
+            for (i, ring) in object.iter().enumerate() {
+                if i > 0 {
+                    wkt.push_str(", ");
+                }
+                wkt.push('(');
+                for (j, p) in ring.iter().enumerate() {
+                    if j > 0 {
+                        wkt.push_str(", ");
+                    }
+                    _ = write!(wkt, "{} {}", p.x, p.y);
+                }
+                wkt.push(')');
+            }
+            wkt.push(')');
+        };
+
+        let objects = self.polygons(edges);
+        let mut wkt = String::new();
+        match objects.as_slice() {
+            [] => wkt.push_str("MULTIPOLYGON EMPTY"),
+            [object] => {
+                wkt.push_str("POLYGON ");
+                polygon(&mut wkt, object);
+            }
+            objects => {
+                wkt.push_str("MULTIPOLYGON (");
+                for (i, object) in objects.iter().enumerate() {
+                    if i > 0 {
+                        wkt.push_str(", ");
+                    }
+                    polygon(&mut wkt, object);
+                }
+                wkt.push(')');
+            }
+        }
+        wkt
+    }
+}
+
+/// Traces the edges between opaque and empty pixels into rings, grouped by object.
+///
+/// # Returns
+///
+/// A vector with, for each object, its outline followed by its holes, with a point at each corner.
+fn pixel_rings<I>(image: &I) -> Vec<Vec<Vec<UVec2>>>
+where
+    I: GenericImageView<Pixel = Bit>,
+{
+    let (width, height) = image.dimensions();
+    let opaque = |x: Option<u32>, y: Option<u32>| {
+        x.zip(y)
+            .is_some_and(|(x, y)| x < width && y < height && *image.get_pixel(x, y))
+    };
+
+    // Edges go clockwise around each pixel (with y pointing down), so the object is on their right,
+    // outlines are clockwise and holes counter-clockwise.
+    let mut edges = Vec::new();
+    for (x, y, pixel) in image.pixels() {
+        if !*pixel {
+            continue;
+        }
+        let corners = [(x, y), (x + 1, y), (x + 1, y + 1), (x, y + 1)].map(UVec2::from);
+        let sides = [
+            (Some(x), y.checked_sub(1)),
+            (x.checked_add(1), Some(y)),
+            (Some(x), y.checked_add(1)),
+            (x.checked_sub(1), Some(y)),
+        ];
+        for (side, (nx, ny)) in sides.into_iter().enumerate() {
+            if !opaque(nx, ny) {
+                edges.push((corners[side], corners[(side + 1) % 4], UVec2::new(x, y)));
+            }
+        }
+    }
+    let mut starting: HashMap<UVec2, Vec<usize>> = HashMap::new();
+    for (i, &(start, ..)) in edges.iter().enumerate() {
+        starting.entry(start).or_default().push(i);
+    }
+
+    let labels = Labels::new(image, true, true);
+    let mut objects = vec![Vec::new(); labels.components.len()];
+    let mut used = vec![false; edges.len()];
+    for first in 0..edges.len() {
+        if used[first] {
+            continue;
+        }
+        let mut ring = Vec::new();
+        let mut edge = first;
+        loop {
+            used[edge] = true;
+            let (start, end, _) = edges[edge];
+            ring.push(start);
+            // Two edges leave the corners shared by pixels touching diagonally, the one going on
+            // around the other pixel is taken so that they stay in the same ring.
+            let direction = end.as_ivec2() - start.as_ivec2();
+            edge = *starting[&end]
+                .iter()
+                .min_by_key(|&&next| {
+                    let (next_start, next_end, _) = edges[next];
+                    direction.perp_dot(next_end.as_ivec2() - next_start.as_ivec2()) >= 0
+                })
+                .unwrap();
+            if edge == first {
+                break;
+            }
+        }
+        let len = ring.len();
+        let corners = (0..len)
+            .filter(|&i| {
+                let (previous, point, next) =
+                    (ring[(i + len - 1) % len], ring[i], ring[(i + 1) % len]);
+                point.as_ivec2() - previous.as_ivec2() != next.as_ivec2() - point.as_ivec2()
+            })
+            .map(|i| ring[i])
+            .collect();
+        // The first ring found of each object goes along the top of its top left pixel, the outline.
+        let pixel = edges[first].2;
+        if let Some(label) = labels.get(pixel.x, pixel.y) {
+            objects[label].push(corners);
+        }
+    }
+    objects
+}
+
+/// The signed area of a polygon in double precision, positive for counter-clockwise polygons.
+fn signed_area(polygon: &[DVec2]) -> f64 {
+    polygon
+        .iter()
+        .zip(polygon.iter().cycle().skip(1))
+        .map(|(a, b)| a.perp_dot(*b))
+        .sum::<f64>()
+        / 2.
+}
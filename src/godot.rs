@@ -0,0 +1,185 @@
+use std::fmt::Write;
+
+use binary_image::Bit;
+use image::GenericImageView;
+
+use crate::{Edges, Vec2};
+
+/// Exports traced outlines as [Godot](https://godotengine.org/) 4 text scenes and resources.
+///
+/// Outlines come from [`Edges::multi_translated`] with y flipped to point down like in Godot.
+/// By default they are centered like a `Sprite2D` with `centered` on, otherwise they start
+/// from the top left corner of the image.
+///
+/// # Example
+///
+/// ```
+/// # use edges::{godot::Scene, Edges};
+/// # let image = image::open("assets/boulders.png").unwrap();
+/// let edges = Edges::from(image);
+/// let tscn = Scene::new().name("Boulders").occluders(false).to_tscn(&edges);
+/// let occluders = Scene::new().to_tres(&edges);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Scene {
+    name: String,
+    centered: bool,
+    collisions: bool,
+    occluders: bool,
+}
+
+impl Default for Scene {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Scene {
+    /// Creates an exporter of centered collision and occluder polygons under a root named `Edges`.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            name: String::from("Edges"),
+            centered: true,
+            collisions: true,
+            occluders: true,
+        }
+    }
+
+    /// Sets the name of the root node of scenes.
+    #[inline]
+    #[must_use]
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    /// Sets whether points are centered on the image, like the `centered` property of a `Sprite2D`.
+    #[inline]
+    #[must_use]
+    pub fn centered(mut self, centered: bool) -> Self {
+        self.centered = centered;
+        self
+    }
+
+    /// Sets whether scenes get a `CollisionPolygon2D` node per outline.
+    #[inline]
+    #[must_use]
+    pub fn collisions(mut self, collisions: bool) -> Self {
+        self.collisions = collisions;
+        self
+    }
+
+    /// Sets whether scenes get a `LightOccluder2D` node with an `OccluderPolygon2D` per outline.
+    #[inline]
+    #[must_use]
+    pub fn occluders(mut self, occluders: bool) -> Self {
+        self.occluders = occluders;
+        self
+    }
+
+    /// The outlines in Godot's coordinates, with y pointing down.
+    fn polygons<I>(&self, edges: &Edges<I>) -> Vec<Vec<Vec2>>
+    where
+        I: GenericImageView<Pixel = Bit>,
+    {
+        let corner = if self.centered {
+            Vec2::ZERO
+        } else {
+            edges.anchor().size().unwrap_or_default().as_vec2() / 2.
+        };
+        edges
+            .multi_translated()
+            .into_iter()
+            .map(|polygon| {
+                polygon
+                    .into_iter()
+                    .map(|p| Vec2::new(p.x, -p.y) + corner)
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Writes a scene (`.tscn`) with a `Node2D` root holding the nodes of every outline.
+    #[must_use]
+    pub fn to_tscn<I>(&self, edges: &Edges<I>) -> String
+    where
+        I: GenericImageView<Pixel = Bit>,
+    {
+        let polygons = self.polygons(edges);
+        let occluders = if self.occluders { polygons.len() } else { 0 };
+        let mut tscn = String::new();
+        _ = writeln!(tscn, "[gd_scene load_steps={} format=3]\n", occluders + 1);
+        for (i, polygon) in polygons.iter().take(occluders).enumerate() {
+            _ = writeln!(
+                tscn,
+                "[sub_resource type=\"OccluderPolygon2D\" id=\"OccluderPolygon2D_{}\"]",
+                i + 1
+            );
+            _ = writeln!(tscn, "polygon = {}\n", packed(polygon));
+        }
+        _ = writeln!(
+            tscn,
+            "[node name=\"{}\" type=\"Node2D\"]",
+            escape(&self.name)
+        );
+        for (i, polygon) in polygons.iter().enumerate() {
+            if self.collisions {
+                _ = writeln!(
+                    tscn,
+                    "\n[node name=\"CollisionPolygon2D{}\" type=\"CollisionPolygon2D\" parent=\".\"]",
+                    i + 1
+                );
+                _ = writeln!(tscn, "polygon = {}", packed(polygon));
+            }
+            if self.occluders {
+                _ = writeln!(
+                    tscn,
+                    "\n[node name=\"LightOccluder2D{}\" type=\"LightOccluder2D\" parent=\".\"]",
+                    i + 1
+                );
+                _ = writeln!(
+                    tscn,
+                    "occluder = SubResource(\"OccluderPolygon2D_{}\")",
+                    i + 1
+                );
+            }
+        }
+        tscn
+    }
+
+    /// Writes an `OccluderPolygon2D` resource (`.tres`) for every outline.
+    ///
+    /// # Returns
+    ///
+    /// A vector with the text of a resource for each outline, in the order of [`Edges::multi_translated`].
+    #[must_use]
+    pub fn to_tres<I>(&self, edges: &Edges<I>) -> Vec<String>
+    where
+        I: GenericImageView<Pixel = Bit>,
+    {
+        self.polygons(edges)
+            .iter()
+            .map(|polygon| {
+                format!(
+                    "[gd_resource type=\"OccluderPolygon2D\" format=3]\n\n[resource]\npolygon = {}\n",
+                    packed(polygon)
+                )
+            })
+            .collect()
+    }
+}
+
+/// Writes points as a `PackedVector2Array`.
+fn packed(polygon: &[Vec2]) -> String {
+    let coordinates: Vec<String> = polygon
+        .iter()
+        .map(|p| format!("{}, {}", p.x, p.y))
+        .collect();
+    format!("PackedVector2Array({})", coordinates.join(", "))
+}
+
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
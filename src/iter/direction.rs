@@ -5,7 +5,7 @@ use super::Neighbors;
 use crate::UVec2;
 use Direction::{East, North, Northeast, Northwest, South, Southeast, Southwest, West};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Direction {
     North,
     South,
@@ -0,0 +1,87 @@
+use super::label::{Component, Labels};
+use crate::UVec2;
+
+/// Options for skipping objects before they are traced.
+///
+/// Objects are the groups of opaque pixels in the image connected through their sides or corners.
+/// Their area (pixel count) and bounding box are measured with a single pass over the image,
+/// so rejected objects never pay the cost of tracing.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Filter {
+    min_area: Option<u32>,
+    max_area: Option<u32>,
+    min_size: Option<UVec2>,
+    max_objects: Option<usize>,
+}
+
+impl Filter {
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Skips objects with less than `area` opaque pixels.
+    #[inline]
+    #[must_use]
+    pub fn min_area(mut self, area: u32) -> Self {
+        self.min_area = Some(area);
+        self
+    }
+
+    /// Skips objects with more than `area` opaque pixels.
+    #[inline]
+    #[must_use]
+    pub fn max_area(mut self, area: u32) -> Self {
+        self.max_area = Some(area);
+        self
+    }
+
+    /// Skips objects whose bounding box is narrower than `width` or shorter than `height` pixels.
+    #[inline]
+    #[must_use]
+    pub fn min_size(mut self, width: u32, height: u32) -> Self {
+        self.min_size = Some(UVec2::new(width, height));
+        self
+    }
+
+    /// Keeps at most `count` objects, preferring the largest ones.
+    ///
+    /// The objects kept are still traced in the usual order, not sorted by area.
+    #[inline]
+    #[must_use]
+    pub fn max_objects(mut self, count: usize) -> Self {
+        self.max_objects = Some(count);
+        self
+    }
+
+    #[inline]
+    fn accepts(&self, object: &Component) -> bool {
+        let size = object.max - object.min + UVec2::ONE;
+        self.min_area.is_none_or(|min| object.area >= min)
+            && self.max_area.is_none_or(|max| object.area <= max)
+            && self
+                .min_size
+                .is_none_or(|min| size.x >= min.x && size.y >= min.y)
+    }
+
+    /// Removes the pixels of rejected objects from `pixels`.
+    pub(super) fn apply(&self, labels: &Labels, pixels: &mut Vec<UVec2>) {
+        if *self == Self::default() {
+            return;
+        }
+        let objects = &labels.components;
+        let mut accepted: Vec<usize> = (0..objects.len())
+            .filter(|&i| self.accepts(&objects[i]))
+            .collect();
+        if let Some(count) = self.max_objects {
+            accepted.sort_by_key(|&i| std::cmp::Reverse(objects[i].area));
+            accepted.truncate(count);
+        }
+        let mut keep = vec![false; objects.len()];
+        for i in accepted {
+            keep[i] = true;
+        }
+        pixels.retain(|p| labels.get(p.x, p.y).is_some_and(|label| keep[label]));
+    }
+}
@@ -0,0 +1,80 @@
+use binary_image::Bit;
+use image::GenericImageView;
+
+use super::{label::Labels, Edges};
+use crate::{Region, UVec2};
+
+/// The empty pixels of a single group, seen as opaque.
+struct Hole<'a> {
+    labels: &'a Labels,
+    label: usize,
+    size: (u32, u32),
+}
+
+impl GenericImageView for Hole<'_> {
+    type Pixel = Bit;
+    #[inline]
+    fn dimensions(&self) -> (u32, u32) {
+        self.size
+    }
+    #[inline]
+    fn get_pixel(&self, x: u32, y: u32) -> Self::Pixel {
+        Bit(self.labels.get(x, y) == Some(self.label))
+    }
+}
+
+/// Pairs every outline with the holes inside it.
+///
+/// Holes are the groups of empty pixels connected through their sides that do not reach the border
+/// of the image, traced along the centers of their pixels like objects are.
+/// Holes of a single pixel are a polygon with a single point.
+pub(super) fn with_holes<I>(
+    image: &I,
+    objects: &Labels,
+    outlines: Vec<Vec<UVec2>>,
+) -> Vec<Vec<Vec<UVec2>>>
+where
+    I: GenericImageView<Pixel = Bit>,
+{
+    let (width, height) = image.dimensions();
+    let mut owners = vec![None; objects.components.len()];
+    for (i, outline) in outlines.iter().enumerate() {
+        if let Some(label) = outline.first().and_then(|p| objects.get(p.x, p.y)) {
+            owners[label] = Some(i);
+        }
+    }
+    let mut result: Vec<Vec<Vec<UVec2>>> = outlines.into_iter().map(|o| vec![o]).collect();
+
+    let empty = Labels::new(image, false, false);
+    for (label, component) in empty.components.iter().enumerate() {
+        if component.min.x == 0
+            || component.min.y == 0
+            || component.max.x == width - 1
+            || component.max.y == height - 1
+        {
+            continue;
+        }
+        // The pixel above the first one of a hole belongs to the object around it.
+        let above = component.start - UVec2::Y;
+        let Some(owner) = objects
+            .get(above.x, above.y)
+            .and_then(|object| owners[object])
+        else {
+            continue;
+        };
+
+        let hole = Hole {
+            labels: &empty,
+            label,
+            size: (width, height),
+        };
+        let size = component.max - component.min + UVec2::ONE;
+        let region = Region::new(&hole, component.min.x, component.min.y, size.x, size.y);
+        let polygon = Edges::new(&region).next().map_or_else(
+            || vec![component.start],
+            |polygon| polygon.into_iter().map(|p| p + component.min).collect(),
+        );
+        result[owner].push(polygon);
+    }
+    result
+}
@@ -0,0 +1,86 @@
+use binary_image::Bit;
+use image::GenericImageView;
+
+use crate::UVec2;
+
+/// A connected group of pixels.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub(crate) struct Component {
+    /// The first pixel of the group in row-major order.
+    pub start: UVec2,
+    pub area: u32,
+    pub min: UVec2,
+    pub max: UVec2,
+}
+
+/// Connected groups of opaque or empty pixels.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub(crate) struct Labels {
+    width: u32,
+    pixels: Vec<Option<usize>>,
+    pub components: Vec<Component>,
+}
+
+impl Labels {
+    /// Labels the groups of pixels equal to `opaque`, connected through their sides and,
+    /// when `diagonal` is set, through their corners.
+    pub fn new<I>(image: &I, opaque: bool, diagonal: bool) -> Self
+    where
+        I: GenericImageView<Pixel = Bit>,
+    {
+        let (width, height) = image.dimensions();
+        let mut labels = vec![None; width as usize * height as usize];
+        let mut components = Vec::new();
+        let mut stack = Vec::new();
+
+        for (x, y, pixel) in image.pixels() {
+            if *pixel != opaque || labels[index(width, x, y)].is_some() {
+                continue;
+            }
+            let label = components.len();
+            let start = UVec2::new(x, y);
+            let mut component = Component {
+                start,
+                area: 0,
+                min: start,
+                max: start,
+            };
+            labels[index(width, x, y)] = Some(label);
+            stack.push(start);
+            while let Some(p) = stack.pop() {
+                component.area += 1;
+                component.min = component.min.min(p);
+                component.max = component.max.max(p);
+                for ny in p.y.saturating_sub(1)..=(p.y + 1).min(height - 1) {
+                    for nx in p.x.saturating_sub(1)..=(p.x + 1).min(width - 1) {
+                        if !diagonal && nx != p.x && ny != p.y {
+                            continue;
+                        }
+                        let index = index(width, nx, ny);
+                        if labels[index].is_none() && *image.get_pixel(nx, ny) == opaque {
+                            labels[index] = Some(label);
+                            stack.push(UVec2::new(nx, ny));
+                        }
+                    }
+                }
+            }
+            components.push(component);
+        }
+        Self {
+            width,
+            pixels: labels,
+            components,
+        }
+    }
+
+    #[inline]
+    pub fn get(&self, x: u32, y: u32) -> Option<usize> {
+        self.pixels[index(self.width, x, y)]
+    }
+}
+
+/// The index of a pixel in row-major order, computed in `usize` so large images do not overflow.
+#[inline]
+fn index(width: u32, x: u32, y: u32) -> usize {
+    y as usize * width as usize + x as usize
+}
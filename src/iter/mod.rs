@@ -1,12 +1,22 @@
+use std::collections::HashMap;
+
 use binary_image::Bit;
 use image::GenericImageView;
 
-use crate::UVec2;
+use crate::{
+    utils::{bounding_box, canonical},
+    UVec2,
+};
 use direction::Direction;
-pub(self) use neigbors::Neighbors;
+pub use filter::Filter;
+use label::Labels;
+use neigbors::Neighbors;
 use utils::in_polygon;
 
 mod direction;
+mod filter;
+mod holes;
+pub(crate) mod label;
 mod neigbors;
 mod utils;
 
@@ -17,6 +27,8 @@ where
 {
     image: &'a I,
     corners: Vec<UVec2>,
+    pixels: Vec<UVec2>,
+    objects: Labels,
 }
 
 impl<'a, I> Edges<'a, I>
@@ -25,6 +37,7 @@ where
 {
     pub fn new(image: &'a I) -> Self {
         let (width, height) = image.dimensions();
+        let objects = Labels::new(image, true, true);
         Self {
             image,
             corners: (0..height)
@@ -34,43 +47,121 @@ where
                     *image.get_pixel(p.x, p.y) && Neighbors::from_image(image, p.x, p.y).is_corner()
                 })
                 .collect(),
+            pixels: objects
+                .components
+                .iter()
+                .rev()
+                .filter(|object| object.area == 1)
+                .map(|object| object.start)
+                .collect(),
+            objects,
         }
     }
+
+    /// Skips the objects rejected by `filter` before they are traced.
+    #[inline]
+    #[must_use]
+    pub fn with_filter(mut self, filter: Filter) -> Self {
+        filter.apply(&self.objects, &mut self.corners);
+        filter.apply(&self.objects, &mut self.pixels);
+        self
+    }
+
+    /// Traces every object along with the holes inside it.
+    ///
+    /// Holes are traced along the centers of the empty pixels bordering them,
+    /// so they never share points with the outline around them.
+    /// Objects inside a hole are objects of their own.
+    ///
+    /// # Returns
+    ///
+    /// A vector with, for each object, its outline followed by its holes.
+    #[must_use]
+    pub fn with_holes(mut self) -> Vec<Vec<Vec<UVec2>>> {
+        let outlines = self.by_ref().collect();
+        holes::with_holes(self.image, &self.objects, outlines)
+    }
+
+    /// Traces every object and returns them in a canonical order.
+    ///
+    /// Objects are sorted by the top left corner of their bounding box (by y, then x)
+    /// and each of them is put in the form described by [`canonical`].
+    /// This order only depends on the pixels of each object, so it is stable across runs and platforms
+    /// and editing pixels of one object does not reorder or rotate the others.
+    #[must_use]
+    pub fn canonical(self) -> std::vec::IntoIter<Vec<UVec2>> {
+        let mut objects: Vec<Vec<UVec2>> = self.map(canonical).collect();
+        objects.sort_by_cached_key(|object| {
+            let min = bounding_box(object.iter().copied()).map_or(UVec2::ZERO, |(min, _)| min);
+            (
+                min.y,
+                min.x,
+                object.iter().map(UVec2::to_array).collect::<Vec<_>>(),
+            )
+        });
+        objects.into_iter()
+    }
 }
 
-impl<'a, I> Iterator for Edges<'a, I>
+impl<I> Iterator for Edges<'_, I>
 where
     I: GenericImageView<Pixel = Bit>,
 {
     type Item = Vec<UVec2>;
     fn next(&mut self) -> Option<Self::Item> {
         let corners = &mut self.corners;
-        corners.pop().map(|start| {
-            let mut current = start;
-            let mut object = vec![start];
-
-            let neighbors = Neighbors::from_image(self.image, start.x, start.y);
-            let mut previous_direction = Direction::next_direction(None, neighbors);
-
-            loop {
-                let neighbors = Neighbors::from_image(self.image, current.x, current.y);
-                let direction = Direction::next_direction(Some(previous_direction), neighbors);
-
-                current = if previous_direction.reverse() == direction {
-                    *object.last().unwrap()
-                } else {
-                    direction.find_in(current, corners).unwrap_or(start)
-                };
-
-                if *object.last().unwrap() == start && object.contains(&current) {
-                    object.pop();
-                    corners.retain(|p| !(object.contains(p) || in_polygon(p.x, p.y, &object)));
-                    break object;
-                }
+        corners
+            .pop()
+            .map(|start| {
+                let mut current = start;
+                let mut object = vec![start];
+
+                let neighbors = Neighbors::from_image(self.image, start.x, start.y);
+                let mut previous_direction = Direction::next_direction(None, neighbors);
+                let mut steps = HashMap::new();
+
+                loop {
+                    let neighbors = Neighbors::from_image(self.image, current.x, current.y);
+                    let direction = Direction::next_direction(Some(previous_direction), neighbors);
+
+                    current = if previous_direction.reverse() == direction {
+                        *object.last().unwrap()
+                    } else {
+                        direction.find_in(current, corners).unwrap_or(start)
+                    };
+
+                    if *object.last().unwrap() == start && object.contains(&current) {
+                        object.pop();
+                        remove_traced(corners, &self.objects, start, &object);
+                        break object;
+                    }
 
-                previous_direction = direction;
-                object.push(current);
-            }
-        })
+                    // The walk follows the same steps from here on, when they do not go through the start
+                    // it would never end, so the object is closed where it starts repeating.
+                    if let Some(&first) = steps.get(&(current, direction)) {
+                        if !object[first..].contains(&start) {
+                            remove_traced(corners, &self.objects, start, &object);
+                            break object;
+                        }
+                    } else {
+                        steps.insert((current, direction), object.len());
+                    }
+
+                    previous_direction = direction;
+                    object.push(current);
+                }
+            })
+            // Isolated pixels have no corners, each of them is an object with a single point.
+            .or_else(|| self.pixels.pop().map(|pixel| vec![pixel]))
     }
 }
+
+/// Removes the corners of a traced object from the ones left to trace.
+///
+/// Only corners of this object are removed inside it, the other objects there are inside its holes.
+fn remove_traced(corners: &mut Vec<UVec2>, objects: &Labels, start: UVec2, object: &[UVec2]) {
+    let label = objects.get(start.x, start.y);
+    corners.retain(|p| {
+        !(object.contains(p) || (objects.get(p.x, p.y) == label && in_polygon(p.x, p.y, object)))
+    });
+}
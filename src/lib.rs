@@ -4,19 +4,49 @@ use binary_image::{BinaryImage, BinaryView, Bit};
 use image::{DynamicImage, GenericImageView};
 
 #[cfg(feature = "bevy")]
-pub(crate) use bevy_math::prelude::{UVec2, Vec2};
+pub(crate) use bevy_math::{
+    prelude::{UVec2, Vec2, Vec3},
+    Affine2, DAffine2, DVec2, Mat3,
+};
 #[cfg(all(not(feature = "bevy"), feature = "glam-latest"))]
-pub(crate) use glam::{UVec2, Vec2};
+pub(crate) use glam::{Affine2, DAffine2, DVec2, Mat3, UVec2, Vec2, Vec3};
 
 pub extern crate binary_image;
-pub use iter::Edges as EdgesIter;
+pub use iter::{Edges as EdgesIter, Filter};
+pub use region::Region;
 
 pub mod anchor;
+#[cfg(feature = "animation")]
+pub mod animation;
+pub mod boolean;
+pub mod bounds;
+#[cfg(any(feature = "rapier2d", feature = "avian2d"))]
+pub mod collider;
+pub mod extrude;
+pub mod geo;
+pub mod godot;
+pub mod moments;
+pub mod morph;
+pub mod navmesh;
+pub mod normals;
+pub mod occluder;
+pub mod offset;
+pub mod query;
+pub mod raster;
+pub mod resample;
+pub mod sdf;
+pub mod skeleton;
+pub mod smooth;
+pub mod tiled;
+pub mod transform;
+pub mod triangulate;
 pub mod utils;
 
 #[cfg(feature = "bevy")]
 mod bevy;
+mod clip;
 mod iter;
+mod region;
 #[cfg(all(feature = "bevy", test))]
 mod tests;
 
@@ -74,15 +104,269 @@ where
         self.iter().collect()
     }
 
+    /// Retrieves the raw edge points of multiple objects along with the holes inside them.
+    ///
+    /// # Returns
+    ///
+    /// A vector with, for each object, a vector of `UVec2` for its outline followed by one for each of its holes.
+    #[inline]
+    #[must_use]
+    pub fn multi_raw_with_holes(&self) -> Vec<Vec<Vec<UVec2>>> {
+        self.iter().with_holes()
+    }
+
+    /// Translates the edges of multiple objects along with the holes inside them
+    /// into a coordinate system centered at (0, 0).
+    ///
+    /// # Returns
+    ///
+    /// A vector with, for each object, a vector of `Vec2` for its outline followed by one for each of its holes.
+    #[inline]
+    #[must_use]
+    pub fn multi_translated_with_holes(&self) -> Vec<Vec<Vec<Vec2>>> {
+        let anchor = self.anchor();
+        self.multi_raw_with_holes()
+            .into_iter()
+            .map(|object| anchor.translate_polygons(object.into_iter()))
+            .collect()
+    }
+
+    /// Translates the edges of a single image, then applies a transform to them.
+    ///
+    /// # Returns
+    ///
+    /// A vector of `Vec2` representing the transformed edge points.
+    #[inline]
+    #[must_use]
+    pub fn single_transformed(&self, transform: transform::Transform) -> Option<Vec<Vec2>> {
+        self.single_translated()
+            .map(|polygon| transform.apply(&polygon))
+    }
+
+    /// Translates the edges of a single image, then applies a transform to them in double precision.
+    ///
+    /// # Returns
+    ///
+    /// A vector of `DVec2` representing the transformed edge points.
+    #[inline]
+    #[must_use]
+    pub fn single_transformed_f64(&self, transform: transform::Transform) -> Option<Vec<DVec2>> {
+        self.single_translated()
+            .map(|polygon| transform.apply_f64(&polygon))
+    }
+
+    /// Translates the edges of multiple images, then applies a transform to them.
+    ///
+    /// # Returns
+    ///
+    /// A vector of vectors of `Vec2` representing the transformed edge points of each image.
+    #[inline]
+    #[must_use]
+    pub fn multi_transformed(&self, transform: transform::Transform) -> Vec<Vec<Vec2>> {
+        self.multi_translated()
+            .iter()
+            .map(|polygon| transform.apply(polygon))
+            .collect()
+    }
+
+    /// Translates the edges of multiple images, then applies a transform to them in double precision.
+    ///
+    /// # Returns
+    ///
+    /// A vector of vectors of `DVec2` representing the transformed edge points of each image.
+    #[inline]
+    #[must_use]
+    pub fn multi_transformed_f64(&self, transform: transform::Transform) -> Vec<Vec<DVec2>> {
+        self.multi_translated()
+            .iter()
+            .map(|polygon| transform.apply_f64(polygon))
+            .collect()
+    }
+
+    /// Translates the edges of multiple objects along with the holes inside them,
+    /// then applies a transform to them.
+    ///
+    /// # Returns
+    ///
+    /// A vector with, for each object, a vector of `Vec2` for its outline followed by one for each of its holes.
+    #[inline]
+    #[must_use]
+    pub fn multi_transformed_with_holes(
+        &self,
+        transform: transform::Transform,
+    ) -> Vec<Vec<Vec<Vec2>>> {
+        self.multi_translated_with_holes()
+            .iter()
+            .map(|object| {
+                object
+                    .iter()
+                    .map(|polygon| transform.apply(polygon))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Translates the edges of multiple objects along with the holes inside them,
+    /// then applies a transform to them in double precision.
+    ///
+    /// # Returns
+    ///
+    /// A vector with, for each object, a vector of `DVec2` for its outline followed by one for each of its holes.
+    #[inline]
+    #[must_use]
+    pub fn multi_transformed_with_holes_f64(
+        &self,
+        transform: transform::Transform,
+    ) -> Vec<Vec<Vec<DVec2>>> {
+        self.multi_translated_with_holes()
+            .iter()
+            .map(|object| {
+                object
+                    .iter()
+                    .map(|polygon| transform.apply_f64(polygon))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Computes the normals of the translated edges of multiple objects.
+    ///
+    /// # Returns
+    ///
+    /// A vector of `Normals` aligned with the polygons and points of [`Edges::multi_translated`].
+    #[inline]
+    #[must_use]
+    pub fn multi_translated_normals(&self) -> Vec<normals::Normals> {
+        self.multi_translated()
+            .iter()
+            .map(|polygon| normals::Normals::new(polygon, false))
+            .collect()
+    }
+
+    /// Computes the normals of the translated edges of multiple objects along with the holes inside them,
+    /// pointing into the holes.
+    ///
+    /// # Returns
+    ///
+    /// A vector of vectors of `Normals` aligned with the polygons and points of [`Edges::multi_translated_with_holes`].
+    #[inline]
+    #[must_use]
+    pub fn multi_translated_normals_with_holes(&self) -> Vec<Vec<normals::Normals>> {
+        self.multi_translated_with_holes()
+            .iter()
+            .map(|object| normals::object_normals(object))
+            .collect()
+    }
+
+    /// Extrudes the translated objects into a closed mesh `depth` thick, see [`extrude::extrude`].
+    #[inline]
+    #[must_use]
+    pub fn extrude(&self, depth: f32) -> extrude::Extrusion {
+        let size = Vec2::new(self.width() as f32, self.height() as f32);
+        extrude::extrude(&self.multi_translated_with_holes(), depth, size)
+    }
+
+    /// Builds a navigation mesh over the opaque pixels, see [`navmesh::NavMesh::new`].
+    #[inline]
+    #[must_use]
+    pub fn navmesh(&self, agent_radius: f32) -> navmesh::NavMesh {
+        navmesh::NavMesh::new(&self.multi_translated_with_holes(), agent_radius)
+    }
+
+    /// Thins the opaque pixels to a graph of lines, see [`skeleton::Skeleton::new`].
+    #[inline]
+    #[must_use]
+    pub fn skeleton(&self) -> skeleton::Skeleton {
+        skeleton::Skeleton::new(self)
+    }
+
+    /// Exports the translated edges as occluder segments for 2D lighting, see [`occluder::segments`].
+    #[inline]
+    #[must_use]
+    pub fn occluders(&self, facing: Option<Vec2>) -> Vec<occluder::Segment> {
+        occluder::segments(&self.multi_translated_with_holes(), facing)
+    }
+
+    /// Indexes the translated objects to find the one under a point, e.g. for mouse picking.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use edges::Edges;
+    /// # let image = image::open("assets/boulders.png").unwrap();
+    /// let edges = Edges::from(image);
+    /// let boulder = edges.hit_tester().hit_test([10., -5.].into());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn hit_tester(&self) -> query::HitTester {
+        query::HitTester::new(self.multi_translated_with_holes())
+    }
+
     #[inline]
     #[must_use]
-    pub fn iter(&self) -> iter::Edges<I> {
+    pub fn iter(&self) -> iter::Edges<'_, I> {
         self.into_iter()
     }
 
+    /// Iterates over the objects accepted by `filter`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use edges::{Edges, Filter};
+    /// # let image = image::open("assets/boulders.png").unwrap();
+    /// let edges = Edges::from(image);
+    /// let filter = Filter::new().min_area(16).max_objects(3);
+    /// let boulders = edges.anchor().translate_polygons(edges.filtered(filter));
+    /// assert!(boulders.len() <= 3);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn filtered(&self, filter: Filter) -> iter::Edges<'_, I> {
+        self.iter().with_filter(filter)
+    }
+
+    /// Generates the signed distance field of the image, see [`sdf::Sdf`] for the options.
+    #[inline]
+    #[must_use]
+    pub fn sdf(&self, options: sdf::Sdf) -> sdf::DistanceField {
+        options.generate(self)
+    }
+
+    /// Resamples the translated edges of multiple images, see [`resample::Resample`] for the options.
+    ///
+    /// # Returns
+    ///
+    /// A vector of vectors of `Vec2` with the resampled points of each image.
+    #[inline]
+    #[must_use]
+    pub fn multi_resampled(&self, options: resample::Resample) -> Vec<Vec<Vec2>> {
+        self.multi_translated()
+            .iter()
+            .map(|polygon| options.apply(polygon))
+            .collect()
+    }
+
+    /// Restricts the edges to the `width` x `height` rectangle whose top left corner is at (`x`, `y`).
+    ///
+    /// Raw points are local to the rectangle and translated points are anchored to its center,
+    /// use [`Edges::multi_raw_in_parent`] and [`Edges::multi_translated_in_parent`]
+    /// to get points in this image's coordinates.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the rectangle is not fully inside the image.
+    #[inline]
+    #[must_use]
+    pub fn in_rect(&self, x: u32, y: u32, width: u32, height: u32) -> Edges<Region<'_, I>> {
+        Edges(Region::new(&self.0, x, y, width, height))
+    }
+
+    /// The anchor used to translate the edges of this image.
     #[inline]
     #[must_use]
-    fn anchor(&self) -> anchor::Anchor {
+    pub fn anchor(&self) -> anchor::Anchor {
         anchor::Anchor::Center(self.height(), self.width())
     }
 }
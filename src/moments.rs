@@ -0,0 +1,263 @@
+use binary_image::Bit;
+use image::GenericImageView;
+
+use crate::{DVec2, Vec2};
+
+/// Image moments of a shape, named like in `OpenCV`: `mpq` are the raw moments, `mupq` the central
+/// moments around the centroid and `nupq` the central moments normalized for scale.
+///
+/// Moments are computed in the coordinates of their source, so orientations are counter-clockwise
+/// for translated polygons (y pointing up) and clockwise on screen for masks (y pointing down).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Moments {
+    pub m00: f64,
+    pub m10: f64,
+    pub m01: f64,
+    pub m20: f64,
+    pub m11: f64,
+    pub m02: f64,
+    pub m30: f64,
+    pub m21: f64,
+    pub m12: f64,
+    pub m03: f64,
+    pub mu20: f64,
+    pub mu11: f64,
+    pub mu02: f64,
+    pub mu30: f64,
+    pub mu21: f64,
+    pub mu12: f64,
+    pub mu03: f64,
+    pub nu20: f64,
+    pub nu11: f64,
+    pub nu02: f64,
+    pub nu30: f64,
+    pub nu21: f64,
+    pub nu12: f64,
+    pub nu03: f64,
+}
+
+impl Moments {
+    /// Computes the moments of the opaque pixels of an image, each pixel counting as a point
+    /// at its raw coordinates.
+    #[must_use]
+    pub fn from_mask<I>(image: &I) -> Self
+    where
+        I: GenericImageView<Pixel = Bit>,
+    {
+        let mut raw = [0.; 10];
+        for (x, y, pixel) in image.pixels() {
+            if *pixel {
+                add(&mut raw, point(f64::from(x), f64::from(y)), 1.);
+            }
+        }
+        Self::from_raw(raw)
+    }
+
+    /// Computes the moments of the area inside a closed polygon, whatever its winding.
+    #[must_use]
+    pub fn from_polygon(polygon: &[Vec2]) -> Self {
+        Self::from_raw(polygon_raw(polygon))
+    }
+
+    /// Computes the moments of an object made of an outline followed by its holes,
+    /// as returned by [`Edges::multi_translated_with_holes`](crate::Edges::multi_translated_with_holes).
+    #[must_use]
+    pub fn from_object(object: &[Vec<Vec2>]) -> Self {
+        let mut raw = [0.; 10];
+        for (i, polygon) in object.iter().enumerate() {
+            add(
+                &mut raw,
+                polygon_raw(polygon),
+                if i == 0 { 1. } else { -1. },
+            );
+        }
+        Self::from_raw(raw)
+    }
+
+    fn from_raw(raw: [f64; 10]) -> Self {
+        let [m00, m10, m01, m20, m11, m02, m30, m21, m12, m03] = raw;
+        let mut moments = Self {
+            m00,
+            m10,
+            m01,
+            m20,
+            m11,
+            m02,
+            m30,
+            m21,
+            m12,
+            m03,
+            ..Self::default()
+        };
+        if m00 == 0. {
+            return moments;
+        }
+        let (x, y) = (m10 / m00, m01 / m00);
+        moments.mu20 = m20 - x * m10;
+        moments.mu11 = m11 - x * m01;
+        moments.mu02 = m02 - y * m01;
+        moments.mu30 = m30 - 3. * x * m20 + 2. * x * x * m10;
+        moments.mu21 = m21 - 2. * x * m11 - y * m20 + 2. * x * x * m01;
+        moments.mu12 = m12 - 2. * y * m11 - x * m02 + 2. * y * y * m10;
+        moments.mu03 = m03 - 3. * y * m02 + 2. * y * y * m01;
+
+        let (second, third) = (m00 * m00, m00 * m00 * m00.sqrt());
+        moments.nu20 = moments.mu20 / second;
+        moments.nu11 = moments.mu11 / second;
+        moments.nu02 = moments.mu02 / second;
+        moments.nu30 = moments.mu30 / third;
+        moments.nu21 = moments.mu21 / third;
+        moments.nu12 = moments.mu12 / third;
+        moments.nu03 = moments.mu03 / third;
+        moments
+    }
+
+    /// The area of the shape.
+    #[inline]
+    #[must_use]
+    pub fn area(&self) -> f64 {
+        self.m00
+    }
+
+    /// The center of mass of the shape, or `None` if it has no area.
+    #[inline]
+    #[must_use]
+    pub fn centroid(&self) -> Option<DVec2> {
+        (self.m00 != 0.).then(|| DVec2::new(self.m10, self.m01) / self.m00)
+    }
+
+    /// The seven Hu invariants, which stay the same when the shape is moved, scaled or rotated.
+    /// The last one changes sign when the shape is mirrored.
+    #[must_use]
+    pub fn hu(&self) -> [f64; 7] {
+        let (n20, n11, n02) = (self.nu20, self.nu11, self.nu02);
+        let (n30, n21, n12, n03) = (self.nu30, self.nu21, self.nu12, self.nu03);
+        let (a, b) = (n30 + n12, n21 + n03);
+        let (c, d) = (n30 - 3. * n12, 3. * n21 - n03);
+        [
+            n20 + n02,
+            (n20 - n02).powi(2) + 4. * n11 * n11,
+            c * c + d * d,
+            a * a + b * b,
+            c * a * (a * a - 3. * b * b) + d * b * (3. * a * a - b * b),
+            (n20 - n02) * (a * a - b * b) + 4. * n11 * a * b,
+            d * a * (a * a - 3. * b * b) - c * b * (3. * a * a - b * b),
+        ]
+    }
+
+    /// The angle of the principal axis of the shape from the x axis, in radians between -π/2 and π/2.
+    #[inline]
+    #[must_use]
+    pub fn orientation(&self) -> f64 {
+        0.5 * (2. * self.mu11).atan2(self.mu20 - self.mu02)
+    }
+
+    /// How elongated the shape is, from 0 for shapes as wide in every direction like circles and squares
+    /// to 1 for lines, computed from the spread of the shape along its principal axes.
+    #[must_use]
+    pub fn eccentricity(&self) -> f64 {
+        let mean = f64::midpoint(self.mu20, self.mu02);
+        let spread = (((self.mu20 - self.mu02) / 2.).powi(2) + self.mu11 * self.mu11).sqrt();
+        let (major, minor) = (mean + spread, mean - spread);
+        if major > 0. {
+            (1. - minor / major).max(0.).sqrt()
+        } else {
+            0.
+        }
+    }
+}
+
+/// Compares two shapes with their Hu invariants on a log scale, like `CONTOURS_MATCH_I1` in `OpenCV`.
+///
+/// # Example
+///
+/// ```
+/// # use edges::{moments::{match_shapes, Moments}, Edges};
+/// # let image = image::open("assets/boulders.png").unwrap();
+/// let edges = Edges::from(image);
+/// let boulders = edges.multi_translated();
+/// let score = match_shapes(
+///     &Moments::from_polygon(&boulders[0]),
+///     &Moments::from_polygon(&boulders[1]),
+/// );
+/// ```
+///
+/// # Returns
+///
+/// 0 for shapes that only differ by position, scale and rotation, and more the more different they are.
+#[must_use]
+pub fn match_shapes(first: &Moments, second: &Moments) -> f64 {
+    let log = |h: f64| h.signum() * h.abs().log10();
+    first
+        .hu()
+        .into_iter()
+        .zip(second.hu())
+        .filter(|(a, b)| a.abs() > 1e-5 && b.abs() > 1e-5)
+        .map(|(a, b)| (1. / log(a) - 1. / log(b)).abs())
+        .sum()
+}
+
+/// The raw moments of a single point of weight 1.
+fn point(x: f64, y: f64) -> [f64; 10] {
+    [
+        1.,
+        x,
+        y,
+        x * x,
+        x * y,
+        y * y,
+        x * x * x,
+        x * x * y,
+        x * y * y,
+        y * y * y,
+    ]
+}
+
+fn add(raw: &mut [f64; 10], other: [f64; 10], sign: f64) {
+    for (a, b) in raw.iter_mut().zip(other) {
+        *a += sign * b;
+    }
+}
+
+/// The raw moments of the area inside a polygon, with Green's theorem over its edges.
+fn polygon_raw(polygon: &[Vec2]) -> [f64; 10] {
+    let mut raw = [0.; 10];
+    for (a, b) in polygon.iter().zip(polygon.iter().cycle().skip(1)) {
+        let (x0, y0, x1, y1) = (
+            f64::from(a.x),
+            f64::from(a.y),
+            f64::from(b.x),
+            f64::from(b.y),
+        );
+        let cross = x0 * y1 - x1 * y0;
+        add(
+            &mut raw,
+            [
+                cross,
+                cross * (x0 + x1),
+                cross * (y0 + y1),
+                cross * (x0 * x0 + x0 * x1 + x1 * x1),
+                cross * (x0 * (2. * y0 + y1) + x1 * (y0 + 2. * y1)),
+                cross * (y0 * y0 + y0 * y1 + y1 * y1),
+                cross * (x0 + x1) * (x0 * x0 + x1 * x1),
+                cross
+                    * (x0 * x0 * (3. * y0 + y1)
+                        + 2. * x0 * x1 * (y0 + y1)
+                        + x1 * x1 * (y0 + 3. * y1)),
+                cross
+                    * (y0 * y0 * (3. * x0 + x1)
+                        + 2. * y0 * y1 * (x0 + x1)
+                        + y1 * y1 * (x0 + 3. * x1)),
+                cross * (y0 + y1) * (y0 * y0 + y1 * y1),
+            ],
+            1.,
+        );
+    }
+    let scales = [2., 6., 6., 12., 24., 12., 20., 60., 60., 20.];
+    // Clockwise polygons have negative moments, which are flipped so the winding does not matter.
+    let sign = if raw[0] < 0. { -1. } else { 1. };
+    for (moment, scale) in raw.iter_mut().zip(scales) {
+        *moment *= sign / scale;
+    }
+    raw
+}
@@ -0,0 +1,68 @@
+use crate::{resample::Resample, utils::signed_area, Vec2};
+
+/// Resamples two closed contours to `count` points each so that points with the same index match,
+/// e.g. to tween a collider between two frames of an animation.
+///
+/// Both contours are resampled at equal arc lengths, wound the same way, and the second one is
+/// rotated to the start point that best matches the first one once both are centered.
+///
+/// # Example
+///
+/// ```
+/// # use edges::{morph::{correspond, interpolate}, Edges};
+/// # let first = Edges::from(image::open("assets/car.png").unwrap());
+/// # let second = Edges::from(image::open("assets/car.png").unwrap());
+/// let (from, to) = correspond(
+///     &first.single_translated().unwrap(),
+///     &second.single_translated().unwrap(),
+///     64,
+/// );
+/// let halfway = interpolate(&from, &to, 0.5);
+/// ```
+///
+/// # Returns
+///
+/// The resampled contours, empty if either contour is.
+#[must_use]
+pub fn correspond(first: &[Vec2], second: &[Vec2], count: usize) -> (Vec<Vec2>, Vec<Vec2>) {
+    if first.is_empty() || second.is_empty() || count == 0 {
+        return (Vec::new(), Vec::new());
+    }
+    let first = Resample::count(count).apply(first);
+    let mut second = Resample::count(count).apply(second);
+    if (signed_area(&first) < 0.) != (signed_area(&second) < 0.) {
+        second.reverse();
+    }
+
+    let center = |polygon: &[Vec2]| polygon.iter().sum::<Vec2>() / polygon.len() as f32;
+    let (first_center, second_center) = (center(&first), center(&second));
+    let shift = (0..count)
+        .map(|shift| {
+            let cost: f32 = (0..count)
+                .map(|i| {
+                    (first[i] - first_center)
+                        .distance_squared(second[(i + shift) % count] - second_center)
+                })
+                .sum();
+            (shift, cost)
+        })
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map_or(0, |(shift, _)| shift);
+    second.rotate_left(shift);
+    (first, second)
+}
+
+/// Interpolates linearly between matching points of two contours, from `first` at `t = 0`
+/// to `second` at `t = 1`.
+///
+/// # Returns
+///
+/// A vector with as many points as the shortest contour.
+#[must_use]
+pub fn interpolate(first: &[Vec2], second: &[Vec2], t: f32) -> Vec<Vec2> {
+    first
+        .iter()
+        .zip(second)
+        .map(|(a, b)| a.lerp(*b, t))
+        .collect()
+}
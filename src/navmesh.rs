@@ -0,0 +1,280 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap},
+};
+
+use crate::{
+    offset::{offset, Join},
+    triangulate::triangulate,
+    utils::group,
+    Vec2,
+};
+
+/// A convex polygon of walkable area.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Cell {
+    /// Indices into the vertices of the mesh, wound with the inside on their left.
+    pub vertices: Vec<usize>,
+    /// The cell on the other side of the edge from each vertex to the next one, if any.
+    pub neighbors: Vec<Option<usize>>,
+}
+
+/// A navigation mesh made of convex cells sharing their edges.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NavMesh {
+    pub vertices: Vec<Vec2>,
+    pub cells: Vec<Cell>,
+}
+
+impl NavMesh {
+    /// Builds a navigation mesh over walkable objects made of an outline followed by their holes,
+    /// as returned by [`Edges::multi_translated_with_holes`](crate::Edges::multi_translated_with_holes).
+    ///
+    /// The walkable area is first eroded by `agent_radius`, so agents can follow paths without
+    /// overlapping the walls, then triangulated and merged into convex cells where the triangles
+    /// stay convex (Hertel-Mehlhorn).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use edges::{navmesh::NavMesh, Edges};
+    /// # let image = image::open("assets/terrain.png").unwrap();
+    /// let edges = Edges::from(image);
+    /// let navmesh = NavMesh::new(&edges.multi_translated_with_holes(), 0.5);
+    /// let path = navmesh.find_path([-10., -8.].into(), [10., -8.].into());
+    /// ```
+    #[must_use]
+    pub fn new(objects: &[Vec<Vec<Vec2>>], agent_radius: f32) -> Self {
+        let objects = if agent_radius > 0. {
+            let rings: Vec<Vec<Vec2>> = objects.iter().flatten().cloned().collect();
+            group(offset(&rings, -agent_radius, Join::Round))
+        } else {
+            objects.to_vec()
+        };
+
+        let mut vertices = Vec::new();
+        let mut polygons = Vec::new();
+        for object in &objects {
+            let triangles = triangulate(object);
+            let first = vertices.len();
+            vertices.extend(triangles.vertices);
+            polygons.extend(
+                triangles
+                    .indices
+                    .iter()
+                    .map(|triangle| triangle.iter().map(|&i| first + i).collect()),
+            );
+        }
+        let polygons = merge(&vertices, polygons);
+
+        let mut owners = HashMap::new();
+        for (i, polygon) in polygons.iter().enumerate() {
+            for (a, b) in edges(polygon) {
+                owners.insert((a, b), i);
+            }
+        }
+        let cells = polygons
+            .into_iter()
+            .map(|polygon| Cell {
+                neighbors: edges(&polygon)
+                    .map(|(a, b)| owners.get(&(b, a)).copied())
+                    .collect(),
+                vertices: polygon,
+            })
+            .collect();
+        Self { vertices, cells }
+    }
+
+    /// Finds the cell containing a point, including points on its edges.
+    #[must_use]
+    pub fn cell_at(&self, point: Vec2) -> Option<usize> {
+        self.cells.iter().position(|cell| {
+            edges(&cell.vertices).all(|(a, b)| {
+                let (a, b) = (self.vertices[a], self.vertices[b]);
+                (b - a).perp_dot(point - a) >= -1e-4 * (b - a).length()
+            })
+        })
+    }
+
+    /// Finds a path between two points with A* over the cells, then pulls it tight through
+    /// the edges it crosses with the funnel algorithm.
+    ///
+    /// # Returns
+    ///
+    /// The corners of the path from `start` to `goal`, or `None` if either point is outside the mesh
+    /// or they are not connected.
+    #[must_use]
+    pub fn find_path(&self, start: Vec2, goal: Vec2) -> Option<Vec<Vec2>> {
+        let cells = self.find_cells(start, goal)?;
+        let mut portals = vec![(start, start)];
+        for pair in cells.windows(2) {
+            let cell = &self.cells[pair[0]];
+            let i = cell.neighbors.iter().position(|&n| n == Some(pair[1]))?;
+            let (a, b) = (
+                self.vertices[cell.vertices[i]],
+                self.vertices[cell.vertices[(i + 1) % cell.vertices.len()]],
+            );
+            // Leaving a cell wound with its inside on the left, the edge's end is on the left.
+            portals.push((b, a));
+        }
+        portals.push((goal, goal));
+        Some(funnel(&portals))
+    }
+
+    /// The cells crossed going from `start` to `goal`, found with A* from edge midpoint to edge midpoint.
+    fn find_cells(&self, start: Vec2, goal: Vec2) -> Option<Vec<usize>> {
+        let (first, last) = (self.cell_at(start)?, self.cell_at(goal)?);
+        let mut costs = vec![f32::INFINITY; self.cells.len()];
+        let mut positions = vec![start; self.cells.len()];
+        let mut previous = vec![None; self.cells.len()];
+        let mut open = BinaryHeap::new();
+        costs[first] = 0.;
+        // Distances are never negative, so their bits sort like them.
+        open.push(Reverse((start.distance(goal).to_bits(), first)));
+
+        while let Some(Reverse((_, current))) = open.pop() {
+            if current == last {
+                let mut path = vec![last];
+                while let Some(cell) = previous[path[path.len() - 1]] {
+                    path.push(cell);
+                }
+                path.reverse();
+                return Some(path);
+            }
+            let cell = &self.cells[current];
+            for (i, neighbor) in cell.neighbors.iter().enumerate() {
+                let Some(neighbor) = *neighbor else {
+                    continue;
+                };
+                let middle = (self.vertices[cell.vertices[i]]
+                    + self.vertices[cell.vertices[(i + 1) % cell.vertices.len()]])
+                    / 2.;
+                let cost = costs[current] + positions[current].distance(middle);
+                if cost < costs[neighbor] {
+                    costs[neighbor] = cost;
+                    positions[neighbor] = middle;
+                    previous[neighbor] = Some(current);
+                    open.push(Reverse((
+                        (cost + middle.distance(goal)).to_bits(),
+                        neighbor,
+                    )));
+                }
+            }
+        }
+        None
+    }
+}
+
+/// The edges of a polygon as pairs of consecutive indices.
+fn edges(polygon: &[usize]) -> impl Iterator<Item = (usize, usize)> + '_ {
+    polygon
+        .iter()
+        .copied()
+        .zip(polygon.iter().copied().cycle().skip(1))
+}
+
+/// Removes the longest diagonals between polygons first, as long as the polygons they join stay convex.
+fn merge(vertices: &[Vec2], polygons: Vec<Vec<usize>>) -> Vec<Vec<usize>> {
+    let mut polygons: Vec<Option<Vec<usize>>> = polygons.into_iter().map(Some).collect();
+    let mut owners = HashMap::new();
+    for (i, polygon) in polygons.iter().enumerate() {
+        for edge in edges(polygon.as_deref().unwrap_or_default()) {
+            owners.insert(edge, i);
+        }
+    }
+    let mut diagonals: Vec<(usize, usize)> = owners
+        .keys()
+        .copied()
+        .filter(|&(a, b)| a < b && owners.contains_key(&(b, a)))
+        .collect();
+    diagonals.sort_by(|&(a, b), &(c, d)| {
+        let length = |a: usize, b: usize| vertices[a].distance_squared(vertices[b]);
+        length(c, d)
+            .total_cmp(&length(a, b))
+            .then((a, b).cmp(&(c, d)))
+    });
+
+    let convex = |previous: usize, point: usize, next: usize| {
+        let (previous, point, next) = (vertices[previous], vertices[point], vertices[next]);
+        (point - previous).perp_dot(next - point) >= 0.
+    };
+    for (a, b) in diagonals {
+        let (Some(&p), Some(&q)) = (owners.get(&(a, b)), owners.get(&(b, a))) else {
+            continue;
+        };
+        let (Some(first), Some(second)) = (&polygons[p], &polygons[q]) else {
+            continue;
+        };
+        if p == q {
+            continue;
+        }
+        // The first polygon from b to a, followed by the second one strictly between a and b.
+        let from = first.iter().position(|&v| v == b).unwrap_or_default();
+        let to = second.iter().position(|&v| v == a).unwrap_or_default();
+        let mut merged: Vec<usize> = (0..first.len())
+            .map(|i| first[(from + i) % first.len()])
+            .collect();
+        merged.extend((1..second.len() - 1).map(|i| second[(to + i) % second.len()]));
+
+        let corner = first.len() - 1;
+        if convex(merged[corner - 1], a, merged[(corner + 1) % merged.len()])
+            && convex(merged[merged.len() - 1], b, merged[1])
+        {
+            for edge in edges(&merged) {
+                owners.insert(edge, p);
+            }
+            owners.remove(&(a, b));
+            owners.remove(&(b, a));
+            polygons[p] = Some(merged);
+            polygons[q] = None;
+        }
+    }
+    polygons.into_iter().flatten().collect()
+}
+
+/// Pulls a path tight through portals given as left and right points, with the simple stupid
+/// funnel algorithm.
+fn funnel(portals: &[(Vec2, Vec2)]) -> Vec<Vec2> {
+    let cross = |a: Vec2, b: Vec2, c: Vec2| (b - a).perp_dot(c - a);
+    let (mut apex, mut left, mut right) = (portals[0].0, portals[0].0, portals[0].1);
+    let (mut left_index, mut right_index) = (0, 0);
+    let mut path = vec![apex];
+
+    let mut i = 1;
+    while i < portals.len() {
+        let (next_left, next_right) = portals[i];
+        if cross(apex, right, next_right) >= 0. {
+            if apex == right || cross(apex, left, next_right) < 0. {
+                right = next_right;
+                right_index = i;
+            } else {
+                // The right side crossed over the left one, so the left point is a corner.
+                path.push(left);
+                apex = left;
+                (left, right) = (apex, apex);
+                (left_index, right_index) = (left_index, left_index);
+                i = left_index + 1;
+                continue;
+            }
+        }
+        if cross(apex, left, next_left) <= 0. {
+            if apex == left || cross(apex, right, next_left) > 0. {
+                left = next_left;
+                left_index = i;
+            } else {
+                path.push(right);
+                apex = right;
+                (left, right) = (apex, apex);
+                (left_index, right_index) = (right_index, right_index);
+                i = left_index + 1;
+                continue;
+            }
+        }
+        i += 1;
+    }
+    let goal = portals[portals.len() - 1].0;
+    if path.last() != Some(&goal) {
+        path.push(goal);
+    }
+    path
+}
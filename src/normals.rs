@@ -0,0 +1,67 @@
+use crate::{utils::signed_area, Vec2};
+
+/// Unit normals of a polygon, pointing out of the solid region it bounds.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Normals {
+    /// The normal at each point, halfway between the normals of the edges meeting there.
+    pub vertices: Vec<Vec2>,
+    /// The normal of the edge from each point to the next one, the last edge closing the polygon.
+    pub edges: Vec<Vec2>,
+}
+
+impl Normals {
+    /// Computes the normals of a translated polygon, where y points up.
+    ///
+    /// Normals of outlines point away from their inside while normals of holes point into them,
+    /// whichever way the polygon is wound.
+    /// Repeated points get the normal of the closest edges around them, and the tip of a part
+    /// going back and forth over a line points along that line.
+    #[must_use]
+    pub fn new(polygon: &[Vec2], hole: bool) -> Self {
+        let outward = if (signed_area(polygon) < 0.) == hole {
+            1.
+        } else {
+            -1.
+        };
+        let edges: Vec<Vec2> = polygon
+            .iter()
+            .zip(polygon.iter().cycle().skip(1))
+            .map(|(&a, &b)| -(b - a).normalize_or_zero().perp() * outward)
+            .collect();
+
+        let len = edges.len();
+        let vertices = (0..len)
+            .map(|i| {
+                let Some(previous) = (1..=len)
+                    .map(|back| (i + len - back) % len)
+                    .find(|&j| edges[j] != Vec2::ZERO)
+                else {
+                    return Vec2::ZERO;
+                };
+                let next = (0..len)
+                    .map(|ahead| (i + ahead) % len)
+                    .find(|&j| edges[j] != Vec2::ZERO)
+                    .unwrap_or(previous);
+                (edges[previous] + edges[next])
+                    .try_normalize()
+                    .unwrap_or_else(|| edges[previous].perp() * outward)
+            })
+            .collect();
+        Self { vertices, edges }
+    }
+}
+
+/// Computes the normals of an object made of an outline followed by its holes,
+/// as returned by [`Edges::multi_translated_with_holes`](crate::Edges::multi_translated_with_holes).
+///
+/// # Returns
+///
+/// A vector of `Normals` aligned with the polygons of the object.
+#[must_use]
+pub fn object_normals(object: &[Vec<Vec2>]) -> Vec<Normals> {
+    object
+        .iter()
+        .enumerate()
+        .map(|(i, polygon)| Normals::new(polygon, i > 0))
+        .collect()
+}
@@ -0,0 +1,123 @@
+use crate::{normals::object_normals, Vec2};
+
+/// A straight run of edges for shadow casting.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Segment {
+    pub start: Vec2,
+    pub end: Vec2,
+    /// The unit normal pointing out of the solid region.
+    pub normal: Vec2,
+}
+
+/// Turns objects made of an outline followed by their holes into occluder segments.
+///
+/// Consecutive edges going the same way are merged into a single segment, and segments
+/// overlapping on the same line (parts of objects one pixel wide, or sides shared by adjacent objects)
+/// are merged too, keeping the normal of the first one.
+/// With a `facing` direction, only segments whose normal points towards it are kept,
+/// e.g. the edges lit by a directional light are those facing its opposite direction.
+///
+/// # Example
+///
+/// ```
+/// # use edges::{occluder::segments, Edges};
+/// # let image = image::open("assets/boulders.png").unwrap();
+/// let edges = Edges::from(image);
+/// let all = segments(&edges.multi_translated_with_holes(), None);
+/// let top = segments(&edges.multi_translated_with_holes(), Some([0., 1.].into()));
+/// assert!(top.len() < all.len());
+/// ```
+///
+/// # Returns
+///
+/// A vector of `Segment`, in the order of the polygons they come from.
+#[must_use]
+pub fn segments(objects: &[Vec<Vec<Vec2>>], facing: Option<Vec2>) -> Vec<Segment> {
+    let mut merged: Vec<Segment> = Vec::new();
+    for mut segment in objects
+        .iter()
+        .flat_map(|object| object.iter().zip(object_normals(object)))
+        .flat_map(|(polygon, normals)| runs(polygon, &normals.edges))
+        .filter(|segment| facing.is_none_or(|facing| segment.normal.dot(facing) > 0.))
+    {
+        // Merging may make the segment reach others, so it is compared again until nothing overlaps.
+        let mut at = merged.len();
+        while let Some(i) = merged.iter().position(|other| overlap(other, &segment)) {
+            segment = union(merged.remove(i), segment);
+            at = at.min(i);
+        }
+        merged.insert(at.min(merged.len()), segment);
+    }
+    merged
+}
+
+/// Checks whether `second` lies on the line of `first` and shares more than a point with it.
+fn overlap(first: &Segment, second: &Segment) -> bool {
+    let direction = first.end - first.start;
+    let length = direction.length();
+    let on_line = |p: Vec2| {
+        direction.perp_dot(p - first.start).abs() <= 1e-6 * length * (p - first.start).length()
+    };
+    let (a, b) = (
+        direction.dot(second.start - first.start),
+        direction.dot(second.end - first.start),
+    );
+    on_line(second.start)
+        && on_line(second.end)
+        && a.max(b).min(length * length) - a.min(b).max(0.) > 1e-6 * length * length
+}
+
+/// The smallest segment covering two overlapping ones, with the direction and normal of `first`.
+fn union(first: Segment, second: Segment) -> Segment {
+    let direction = first.end - first.start;
+    let along = |p: &Vec2| direction.dot(*p - first.start);
+    let points = [first.start, first.end, second.start, second.end];
+    Segment {
+        start: *points
+            .iter()
+            .min_by(|a, b| along(a).total_cmp(&along(b)))
+            .unwrap(),
+        end: *points
+            .iter()
+            .max_by(|a, b| along(a).total_cmp(&along(b)))
+            .unwrap(),
+        normal: first.normal,
+    }
+}
+
+/// Merges the edges of a polygon into straight runs.
+fn runs(polygon: &[Vec2], normals: &[Vec2]) -> Vec<Segment> {
+    let len = polygon.len();
+    let edges: Vec<usize> = (0..len)
+        .filter(|&i| polygon[i] != polygon[(i + 1) % len])
+        .collect();
+    let straight = |a: usize, b: usize| {
+        let first = polygon[(a + 1) % len] - polygon[a];
+        let second = polygon[(b + 1) % len] - polygon[b];
+        first.dot(second) > 0.
+            && first.perp_dot(second).abs() <= 1e-6 * first.length() * second.length()
+    };
+
+    // Starts at an edge which does not continue the previous one, so runs never wrap around.
+    let Some(first) =
+        (0..edges.len()).find(|&i| !straight(edges[(i + edges.len() - 1) % edges.len()], edges[i]))
+    else {
+        return Vec::new();
+    };
+
+    let mut segments: Vec<Segment> = Vec::new();
+    let mut previous = None;
+    for i in (0..edges.len()).map(|i| edges[(first + i) % edges.len()]) {
+        let end = polygon[(i + 1) % len];
+        match (segments.last_mut(), previous) {
+            (Some(segment), Some(previous)) if straight(previous, i) => segment.end = end,
+            _ => segments.push(Segment {
+                start: polygon[i],
+                end,
+                normal: normals[i],
+            }),
+        }
+        previous = Some(i);
+    }
+    segments
+}
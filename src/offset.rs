@@ -0,0 +1,117 @@
+use std::f64::consts::PI;
+
+use crate::{clip, utils::orient, DVec2, Vec2};
+
+/// How the corners of an offset polygon are joined.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Join {
+    /// Extends the edges until they meet, falling back to [`Join::Square`]
+    /// when the tip would be farther than `limit` times the distance from the corner.
+    Miter(f32),
+    /// Rounds corners with an arc centered on the original corner.
+    Round,
+    /// Cuts corners at the offset distance from the original corner.
+    Square,
+}
+
+impl Default for Join {
+    fn default() -> Self {
+        Self::Miter(2.)
+    }
+}
+
+/// Offsets polygons, moving their edges outwards by `distance`, or inwards when `distance` is negative.
+///
+/// Polygons nested inside an odd number of others are treated as holes, which shrink when the outlines grow.
+/// Overlapping results are merged and outlines thinner than twice the distance split or vanish when shrinking.
+///
+/// # Example
+///
+/// ```
+/// # use edges::{offset::{offset, Join}, Edges};
+/// # let image = image::open("assets/car.png").unwrap();
+/// let edges = Edges::from(image);
+/// let hitbox = offset(&edges.multi_translated(), -2., Join::Round);
+/// ```
+///
+/// # Returns
+///
+/// A vector of vectors of `Vec2`, counter-clockwise outlines (when y points up) followed by clockwise holes.
+#[must_use]
+pub fn offset(polygons: &[Vec<Vec2>], distance: f32, join: Join) -> Vec<Vec<Vec2>> {
+    let polygons = clip::to_f64(&orient(polygons.to_vec()));
+    let distance = f64::from(distance);
+    let rings: Vec<Vec<DVec2>> = polygons
+        .iter()
+        .filter(|ring| !ring.is_empty())
+        .map(|ring| offset_ring(ring, distance, join))
+        .collect();
+    clip::resolve(&[&rings], |windings| windings[0] > 0)
+}
+
+/// Offsets a single ring without resolving the loops created at its corners.
+fn offset_ring(ring: &[DVec2], distance: f64, join: Join) -> Vec<DVec2> {
+    if ring.len() == 1 {
+        let center = ring[0];
+        return if distance > 0. {
+            arc(center, DVec2::X, 2. * PI, distance)
+        } else {
+            Vec::new()
+        };
+    }
+
+    let side = distance.signum();
+    let radius = distance.abs();
+    let mut result = Vec::new();
+    for i in 0..ring.len() {
+        let previous = ring[(i + ring.len() - 1) % ring.len()];
+        let point = ring[i];
+        let next = ring[(i + 1) % ring.len()];
+        let (incoming, outgoing) = ((point - previous).normalize(), (next - point).normalize());
+        // Normals facing the side the ring is moved to.
+        let (n1, n2) = (-incoming.perp() * side, -outgoing.perp() * side);
+        let sin = incoming.perp_dot(outgoing) * side;
+        let cos = incoming.dot(outgoing);
+
+        if cos > 1. - 1e-9 {
+            result.push(point + n1 * radius);
+        } else if sin < -1e-9 || (sin.abs() <= 1e-9 && distance < 0.) {
+            // Concave corner, the loop formed here is removed when resolving.
+            result.extend([point + n1 * radius, point, point + n2 * radius]);
+        } else {
+            match join {
+                Join::Miter(limit) if 1. + cos >= 2. / f64::from(limit).powi(2) => {
+                    result.push(point + (n1 + n2) * radius / (1. + cos));
+                }
+                Join::Round => {
+                    let angle = n1.perp_dot(n2).atan2(cos).abs() * side;
+                    result.extend(arc(point, n1, angle, radius));
+                }
+                Join::Miter(_) | Join::Square => {
+                    let bisector = (n1 + n2).try_normalize().unwrap_or(incoming);
+                    let first = radius * (1. - n1.dot(bisector)) / incoming.dot(bisector);
+                    let second = radius * (1. - n2.dot(bisector)) / outgoing.dot(bisector);
+                    result.extend([
+                        point + n1 * radius + incoming * first,
+                        point + n2 * radius + outgoing * second,
+                    ]);
+                }
+            }
+        }
+    }
+    result
+}
+
+/// Points of the arc around `center` starting in direction `from` and turning by `angle` radians.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn arc(center: DVec2, from: DVec2, angle: f64, radius: f64) -> Vec<DVec2> {
+    // Keeps the arc within 1% of the radius from a true circle.
+    let step = 2. * 0.99f64.acos();
+    let steps = (angle.abs() / step).ceil().max(1.) as u32;
+    (0..=steps)
+        .map(|i| {
+            let rotation = DVec2::from_angle(angle * f64::from(i) / f64::from(steps));
+            center + rotation.rotate(from) * radius
+        })
+        .collect()
+}
@@ -0,0 +1,142 @@
+use crate::Vec2;
+
+/// Checks whether a point is inside a polygon or on its boundary.
+///
+/// Uses the even-odd rule, so the polygon may be wound either way.
+#[must_use]
+pub fn point_in_polygon(point: Vec2, polygon: &[Vec2]) -> bool {
+    let mut inside = false;
+    for (&a, &b) in polygon.iter().zip(polygon.iter().cycle().skip(1)) {
+        if on_segment(point, a, b) {
+            return true;
+        }
+        // Half-open on y, so horizontal edges and vertices shared by two edges are counted once.
+        if (a.y > point.y) != (b.y > point.y)
+            && point.x < a.x + (point.y - a.y) * (b.x - a.x) / (b.y - a.y)
+        {
+            inside = !inside;
+        }
+    }
+    inside
+}
+
+/// Checks whether a point is inside an object made of an outline followed by its holes,
+/// as returned by [`Edges::multi_translated_with_holes`](crate::Edges::multi_translated_with_holes).
+///
+/// Points on the outline are inside, points on or in a hole are not.
+#[must_use]
+pub fn contains(point: Vec2, object: &[Vec<Vec2>]) -> bool {
+    object.split_first().is_some_and(|(outline, holes)| {
+        point_in_polygon(point, outline) && !holes.iter().any(|hole| point_in_polygon(point, hole))
+    })
+}
+
+#[inline]
+fn on_segment(point: Vec2, a: Vec2, b: Vec2) -> bool {
+    let (min, max) = (a.min(b), a.max(b));
+    point.cmpge(min).all()
+        && point.cmple(max).all()
+        && (b - a).perp_dot(point - a).abs() <= f32::EPSILON * (b - a).length_squared().max(1.)
+}
+
+/// An index of objects by their bounding boxes, to find the object under a point
+/// without testing every polygon.
+#[derive(Debug, Clone, Default)]
+pub struct HitTester {
+    objects: Vec<Vec<Vec<Vec2>>>,
+    bounds: Vec<(Vec2, Vec2)>,
+    min: Vec2,
+    cell: Vec2,
+    columns: usize,
+    cells: Vec<Vec<usize>>,
+}
+
+impl HitTester {
+    /// Indexes objects made of an outline followed by its holes.
+    #[must_use]
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss
+    )]
+    pub fn new(objects: Vec<Vec<Vec<Vec2>>>) -> Self {
+        let bounds: Vec<(Vec2, Vec2)> = objects
+            .iter()
+            .map(|object| {
+                object
+                    .first()
+                    .into_iter()
+                    .flatten()
+                    .fold((Vec2::INFINITY, Vec2::NEG_INFINITY), |(min, max), &p| {
+                        (min.min(p), max.max(p))
+                    })
+            })
+            .collect();
+        let (min, max) = bounds
+            .iter()
+            .copied()
+            .filter(|(min, max)| min.cmple(*max).all())
+            .reduce(|(a, b), (c, d)| (a.min(c), b.max(d)))
+            .unwrap_or((Vec2::ZERO, Vec2::ZERO));
+
+        let columns = (objects.len() as f32).sqrt().ceil().max(1.) as usize;
+        let cell = ((max - min) / columns as f32).max(Vec2::splat(f32::MIN_POSITIVE));
+        let mut cells = vec![Vec::new(); columns * columns];
+        for (i, (low, high)) in bounds.iter().enumerate() {
+            if !low.cmple(*high).all() {
+                continue;
+            }
+            let first = ((*low - min) / cell).as_uvec2();
+            let last = ((*high - min) / cell).as_uvec2();
+            for y in (first.y as usize).min(columns - 1)..=(last.y as usize).min(columns - 1) {
+                for x in (first.x as usize).min(columns - 1)..=(last.x as usize).min(columns - 1) {
+                    cells[y * columns + x].push(i);
+                }
+            }
+        }
+
+        Self {
+            objects,
+            bounds,
+            min,
+            cell,
+            columns,
+            cells,
+        }
+    }
+
+    /// Finds the object under a point.
+    ///
+    /// # Returns
+    ///
+    /// The index of the first object containing the point, if any.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn hit_test(&self, point: Vec2) -> Option<usize> {
+        let cell = ((point - self.min) / self.cell).floor();
+        if cell.x < 0. || cell.y < 0. {
+            return None;
+        }
+        let (x, y) = (
+            (cell.x as usize).min(self.columns - 1),
+            (cell.y as usize).min(self.columns - 1),
+        );
+        self.cells
+            .get(y * self.columns + x)?
+            .iter()
+            .copied()
+            .find(|&i| {
+                let (min, max) = self.bounds[i];
+                point.cmpge(min).all()
+                    && point.cmple(max).all()
+                    && contains(point, &self.objects[i])
+            })
+    }
+
+    /// The indexed objects.
+    #[inline]
+    #[must_use]
+    pub fn objects(&self) -> &[Vec<Vec<Vec2>>] {
+        &self.objects
+    }
+}
@@ -0,0 +1,147 @@
+use binary_image::{BinaryImage, Bit};
+use image::{GenericImage, GenericImageView};
+
+use crate::{utils::holes, UVec2, Vec2};
+
+/// How overlapping polygons decide which pixels are inside.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum FillRule {
+    /// Inside an odd number of polygons, orientation is ignored.
+    #[default]
+    EvenOdd,
+    /// Inside when the polygons wind around the pixel a non-zero number of times.
+    NonZero,
+}
+
+/// Fills polygons in raw pixel coordinates into a binary image, a pixel being opaque when its center is inside.
+///
+/// Centers lying exactly on a polygon belong to it when it is an outline and not when it is a hole
+/// (nested inside an odd number of other polygons), matching how edges are traced.
+/// Filling traced edges gives back the mask, apart from the pixels the tracer leaves out,
+/// like isolated pixels.
+///
+/// # Example
+///
+/// ```
+/// # use edges::{raster::{diff, rasterize, FillRule}, Edges};
+/// # let image = image::open("assets/car.png").unwrap();
+/// let edges = Edges::from(image);
+/// let polygons: Vec<_> = edges
+///     .multi_raw_with_holes()
+///     .into_iter()
+///     .flatten()
+///     .map(|polygon| polygon.into_iter().map(|p| p.as_vec2()).collect())
+///     .collect();
+/// let filled = rasterize(&polygons, edges.width(), edges.height(), FillRule::EvenOdd);
+/// let difference = diff(&*edges, &filled);
+/// ```
+#[must_use]
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::cast_precision_loss,
+    clippy::float_cmp
+)]
+pub fn rasterize(polygons: &[Vec<Vec2>], width: u32, height: u32, rule: FillRule) -> BinaryImage {
+    let mut image = BinaryImage::new(width, height);
+    let holes = holes(polygons);
+    let mut crossings: Vec<(f32, i32)> = Vec::new();
+    let mut boundary: Vec<Option<bool>> = vec![None; width as usize];
+
+    for y in 0..height {
+        let row = y as f32;
+        crossings.clear();
+        boundary.fill(None);
+        for (polygon, &hole) in polygons.iter().zip(&holes) {
+            for (&a, &b) in polygon.iter().zip(polygon.iter().cycle().skip(1)) {
+                if a.y.min(b.y) > row || a.y.max(b.y) < row {
+                    continue;
+                }
+                let (from, to) = if a.y == b.y {
+                    (a.x.min(b.x), a.x.max(b.x))
+                } else {
+                    let x = a.x + (row - a.y) * (b.x - a.x) / (b.y - a.y);
+                    if (a.y > row) != (b.y > row) {
+                        crossings.push((x, if b.y > a.y { 1 } else { -1 }));
+                    }
+                    (x, x)
+                };
+                let first = from.ceil().max(0.);
+                let last = to.floor().min(width as f32 - 1.);
+                if first <= last {
+                    for owner in &mut boundary[first as usize..=last as usize] {
+                        // Outlines win over holes when both go through a pixel center.
+                        *owner = Some(owner.unwrap_or(true) && hole);
+                    }
+                }
+            }
+        }
+        crossings.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let mut next = 0;
+        let mut winding = 0;
+        for x in 0..width {
+            while next < crossings.len() && crossings[next].0 < x as f32 {
+                winding += crossings[next].1;
+                next += 1;
+            }
+            let inside = match boundary[x as usize] {
+                Some(hole) => !hole,
+                None => match rule {
+                    FillRule::EvenOdd => winding % 2 != 0,
+                    FillRule::NonZero => winding != 0,
+                },
+            };
+            if inside {
+                image.put_pixel(x, y, Bit(true));
+            }
+        }
+    }
+    image
+}
+
+/// Pixels that differ between two binary images.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Diff {
+    /// Pixels opaque in the first image and empty in the second one.
+    pub missing: Vec<UVec2>,
+    /// Pixels empty in the first image and opaque in the second one.
+    pub extra: Vec<UVec2>,
+}
+
+impl Diff {
+    /// The number of differing pixels.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.missing.len() + self.extra.len()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.missing.is_empty() && self.extra.is_empty()
+    }
+}
+
+/// Compares two binary images pixel by pixel, over the area they have in common.
+#[must_use]
+pub fn diff<A, B>(source: &A, other: &B) -> Diff
+where
+    A: GenericImageView<Pixel = Bit>,
+    B: GenericImageView<Pixel = Bit>,
+{
+    let mut diff = Diff::default();
+    let width = source.width().min(other.width());
+    let height = source.height().min(other.height());
+    for y in 0..height {
+        for x in 0..width {
+            match (*source.get_pixel(x, y), *other.get_pixel(x, y)) {
+                (true, false) => diff.missing.push(UVec2::new(x, y)),
+                (false, true) => diff.extra.push(UVec2::new(x, y)),
+                _ => {}
+            }
+        }
+    }
+    diff
+}
@@ -0,0 +1,128 @@
+use binary_image::Bit;
+use image::GenericImageView;
+
+use crate::{anchor::Anchor, Edges, UVec2, Vec2};
+
+/// A rectangular view into an image, used to trace the edges of a part of it without copying pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Region<'a, I: GenericImageView<Pixel = Bit>> {
+    image: &'a I,
+    offset: UVec2,
+    size: UVec2,
+}
+
+impl<'a, I> Region<'a, I>
+where
+    I: GenericImageView<Pixel = Bit>,
+{
+    /// Creates a view of the `width` x `height` rectangle whose top left corner is at (`x`, `y`).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the rectangle is not fully inside the image.
+    #[inline]
+    #[must_use]
+    pub fn new(image: &'a I, x: u32, y: u32, width: u32, height: u32) -> Self {
+        assert!(
+            u64::from(x) + u64::from(width) <= u64::from(image.width())
+                && u64::from(y) + u64::from(height) <= u64::from(image.height()),
+            "Region out of bounds"
+        );
+        Self {
+            image,
+            offset: UVec2::new(x, y),
+            size: UVec2::new(width, height),
+        }
+    }
+
+    /// The position of the top left corner of the region in the parent image.
+    #[inline]
+    #[must_use]
+    pub fn offset(&self) -> UVec2 {
+        self.offset
+    }
+
+    /// The image this region is a view of.
+    #[inline]
+    #[must_use]
+    pub fn parent(&self) -> &'a I {
+        self.image
+    }
+}
+
+impl<I> GenericImageView for Region<'_, I>
+where
+    I: GenericImageView<Pixel = Bit>,
+{
+    type Pixel = Bit;
+    #[inline]
+    fn dimensions(&self) -> (u32, u32) {
+        (self.size.x, self.size.y)
+    }
+    #[inline]
+    fn get_pixel(&self, x: u32, y: u32) -> Self::Pixel {
+        debug_assert!(self.in_bounds(x, y), "Pixel out of bounds");
+        self.image.get_pixel(x + self.offset.x, y + self.offset.y)
+    }
+}
+
+impl<I> Edges<Region<'_, I>>
+where
+    I: GenericImageView<Pixel = Bit>,
+{
+    /// Retrieves the raw edge points of a single object in the region, in the parent image's coordinates.
+    ///
+    /// # Returns
+    ///
+    /// A vector of `UVec2` representing the raw edge points.
+    #[inline]
+    #[must_use]
+    pub fn single_raw_in_parent(&self) -> Option<Vec<UVec2>> {
+        let offset = self.offset();
+        self.single_raw()
+            .map(|polygon| polygon.into_iter().map(|p| p + offset).collect())
+    }
+
+    /// Retrieves the raw edge points of multiple objects in the region, in the parent image's coordinates.
+    ///
+    /// # Returns
+    ///
+    /// A vector of vectors of `UVec2` representing the raw edge points of each object.
+    #[inline]
+    #[must_use]
+    pub fn multi_raw_in_parent(&self) -> Vec<Vec<UVec2>> {
+        let offset = self.offset();
+        self.iter()
+            .map(|polygon| polygon.into_iter().map(|p| p + offset).collect())
+            .collect()
+    }
+
+    /// Retrieves the translated edge points of a single object in the region, anchored to the center of the parent image.
+    ///
+    /// # Returns
+    ///
+    /// A vector of `Vec2` representing the translated edge points.
+    #[inline]
+    #[must_use]
+    pub fn single_translated_in_parent(&self) -> Option<Vec<Vec2>> {
+        self.single_raw_in_parent()
+            .map(|polygon| self.parent_anchor().translate(polygon))
+    }
+
+    /// Retrieves the translated edge points of multiple objects in the region, anchored to the center of the parent image.
+    ///
+    /// # Returns
+    ///
+    /// A vector of vectors of `Vec2` representing the translated edge points of each object.
+    #[inline]
+    #[must_use]
+    pub fn multi_translated_in_parent(&self) -> Vec<Vec<Vec2>> {
+        self.parent_anchor()
+            .translate_polygons(self.multi_raw_in_parent().into_iter())
+    }
+
+    fn parent_anchor(&self) -> Anchor {
+        let parent = self.parent();
+        Anchor::Center(parent.height(), parent.width())
+    }
+}
@@ -0,0 +1,251 @@
+use crate::{UVec2, Vec2};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Target {
+    Count(usize),
+    Spacing(f32),
+}
+
+/// Options to resample contours at equal arc lengths.
+///
+/// # Example
+///
+/// ```
+/// # use edges::{resample::Resample, Edges};
+/// # let image = image::open("assets/car.png").unwrap();
+/// let edges = Edges::from(image);
+/// let car = edges.single_translated().unwrap();
+/// let points = Resample::count(32).apply(&car);
+/// assert_eq!(points.len(), 32);
+/// let corners = Resample::spacing(4.).keep_corners(1.).apply(&car);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Resample {
+    target: Target,
+    corner_angle: Option<f32>,
+    closed: bool,
+}
+
+impl Resample {
+    /// Resamples closed contours to exactly `count` points.
+    #[inline]
+    #[must_use]
+    pub fn count(count: usize) -> Self {
+        Self {
+            target: Target::Count(count),
+            corner_angle: None,
+            closed: true,
+        }
+    }
+
+    /// Resamples closed contours with at most `spacing` between consecutive points,
+    /// as close to it as equal spacing allows.
+    #[inline]
+    #[must_use]
+    pub fn spacing(spacing: f32) -> Self {
+        Self {
+            target: Target::Spacing(spacing),
+            corner_angle: None,
+            closed: true,
+        }
+    }
+
+    /// Keeps the vertices where contours turn by more than `angle` radians, spacing points
+    /// equally between them instead of along the whole contour.
+    ///
+    /// With a count lower than the number of corners, corners are not kept.
+    #[inline]
+    #[must_use]
+    pub fn keep_corners(mut self, angle: f32) -> Self {
+        self.corner_angle = Some(angle);
+        self
+    }
+
+    /// Sets whether contours are closed rings, the default, or open polylines whose ends are kept.
+    #[inline]
+    #[must_use]
+    pub fn closed(mut self, closed: bool) -> Self {
+        self.closed = closed;
+        self
+    }
+
+    /// Resamples a contour, like the polygons of [`Edges::multi_translated`](crate::Edges::multi_translated).
+    ///
+    /// # Returns
+    ///
+    /// A vector of `Vec2` starting at the first point of the contour, or at its first corner
+    /// when corners are kept. Closed contours do not repeat their first point at the end.
+    #[must_use]
+    pub fn apply(&self, polygon: &[Vec2]) -> Vec<Vec2> {
+        let mut points = polygon.to_vec();
+        points.dedup();
+        if self.closed && points.len() > 1 && points.first() == points.last() {
+            points.pop();
+        }
+        let Some(&first) = points.first() else {
+            return Vec::new();
+        };
+        let total = match self.target {
+            Target::Count(count) if self.closed => count,
+            Target::Count(count) => count.saturating_sub(1),
+            Target::Spacing(_) => 1,
+        };
+        if points.len() == 1 || total == 0 {
+            return match self.target {
+                Target::Count(count) => vec![first; count],
+                Target::Spacing(_) => vec![first],
+            };
+        }
+
+        let pieces = self.pieces(&points);
+        let lengths: Vec<f32> = pieces.iter().map(|piece| length(piece)).collect();
+        let segments = match self.target {
+            Target::Count(_) if pieces.len() > total => {
+                // Too many corners for the count, so the contour is resampled as a whole.
+                return Self {
+                    corner_angle: None,
+                    ..*self
+                }
+                .apply(polygon);
+            }
+            Target::Count(_) => distribute(&lengths, total),
+            Target::Spacing(spacing) => lengths
+                .iter()
+                .map(|&length| segments_for(length, spacing))
+                .collect(),
+        };
+
+        let mut resampled: Vec<Vec2> = pieces
+            .iter()
+            .zip(segments)
+            .flat_map(|(piece, segments)| sample(piece, segments))
+            .collect();
+        if !self.closed {
+            resampled.extend(points.last());
+        }
+        resampled
+    }
+
+    /// Resamples a contour of raw pixel coordinates, like the polygons of
+    /// [`Edges::multi_raw`](crate::Edges::multi_raw).
+    ///
+    /// # Returns
+    ///
+    /// A vector of `Vec2` in the same coordinates as the raw points.
+    #[must_use]
+    pub fn apply_raw(&self, polygon: &[UVec2]) -> Vec<Vec2> {
+        let points: Vec<Vec2> = polygon.iter().map(UVec2::as_vec2).collect();
+        self.apply(&points)
+    }
+
+    /// Splits a contour into polylines between corners, each including both of its ends.
+    fn pieces(&self, points: &[Vec2]) -> Vec<Vec<Vec2>> {
+        let len = points.len();
+        let corners: Vec<usize> = self.corner_angle.map_or_else(Vec::new, |angle| {
+            let range = if self.closed { 0..len } else { 1..len - 1 };
+            range
+                .filter(|&i| {
+                    let (previous, next) = (
+                        points[i] - points[(i + len - 1) % len],
+                        points[(i + 1) % len] - points[i],
+                    );
+                    previous.angle_to(next).abs() > angle
+                })
+                .collect()
+        });
+
+        if self.closed {
+            let starts = if corners.is_empty() { vec![0] } else { corners };
+            let mut ends = starts.clone();
+            ends.rotate_left(1);
+            starts
+                .into_iter()
+                .zip(ends)
+                .map(|(from, to)| {
+                    let count = (to + len - from - 1) % len + 1;
+                    (0..=count).map(|i| points[(from + i) % len]).collect()
+                })
+                .collect()
+        } else {
+            let mut bounds = vec![0];
+            bounds.extend(corners);
+            bounds.push(len - 1);
+            bounds
+                .windows(2)
+                .map(|pair| points[pair[0]..=pair[1]].to_vec())
+                .collect()
+        }
+    }
+}
+
+/// The length of a polyline.
+fn length(polyline: &[Vec2]) -> f32 {
+    polyline
+        .windows(2)
+        .map(|pair| pair[0].distance(pair[1]))
+        .sum()
+}
+
+/// The number of equal segments at most `spacing` long covering `length`.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn segments_for(length: f32, spacing: f32) -> usize {
+    if spacing > 0. {
+        ((length / spacing).ceil() as usize).max(1)
+    } else {
+        1
+    }
+}
+
+/// Splits `total` segments between polylines in proportion to their lengths, at least one each.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn distribute(lengths: &[f32], total: usize) -> Vec<usize> {
+    let sum: f32 = lengths.iter().sum();
+    let extra = total - lengths.len();
+    let shares: Vec<f32> = lengths
+        .iter()
+        .map(|&length| {
+            if sum > 0. {
+                length / sum * extra as f32
+            } else {
+                extra as f32 / lengths.len() as f32
+            }
+        })
+        .collect();
+    let mut segments: Vec<usize> = shares.iter().map(|&share| 1 + share as usize).collect();
+    // Hands out what rounding down left to the largest remainders.
+    let mut order: Vec<usize> = (0..lengths.len()).collect();
+    order.sort_by(|&a, &b| {
+        (shares[b] - shares[b].floor()).total_cmp(&(shares[a] - shares[a].floor()))
+    });
+    let left = total - segments.iter().sum::<usize>();
+    for &i in order.iter().cycle().take(left) {
+        segments[i] += 1;
+    }
+    segments
+}
+
+/// Samples a polyline at `segments` equal arc lengths, from its start included to its end excluded.
+fn sample(polyline: &[Vec2], segments: usize) -> Vec<Vec2> {
+    let total = length(polyline);
+    let step = total / segments as f32;
+    let mut points = Vec::with_capacity(segments);
+    let mut edge = 0;
+    let mut start = 0.;
+    for i in 0..segments {
+        let distance = step * i as f32;
+        while edge + 2 < polyline.len()
+            && start + polyline[edge].distance(polyline[edge + 1]) < distance
+        {
+            start += polyline[edge].distance(polyline[edge + 1]);
+            edge += 1;
+        }
+        let (a, b) = (polyline[edge], polyline[edge + 1]);
+        let length = a.distance(b);
+        points.push(if length > 0. {
+            a.lerp(b, ((distance - start) / length).clamp(0., 1.))
+        } else {
+            a
+        });
+    }
+    points
+}
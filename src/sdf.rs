@@ -0,0 +1,381 @@
+#[cfg(feature = "bevy-assets")]
+use bevy_image::prelude::Image as BevyImage;
+use binary_image::Bit;
+use image::{GenericImageView, GrayImage, ImageBuffer, Luma};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::{Edges, UVec2, Vec2};
+
+/// A signed distance field, one distance in source pixels per pixel.
+///
+/// Distances are negative inside objects, positive outside and clamped to the spread.
+pub type DistanceField = ImageBuffer<Luma<f32>, Vec<f32>>;
+
+/// What distances are measured to.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Source {
+    /// The border between opaque and transparent pixels, half a pixel away from their centers.
+    #[default]
+    Mask,
+    /// The traced polygons with their holes, which go through the centers of boundary pixels.
+    Contours,
+}
+
+/// Options for generating a signed distance field.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sdf {
+    size: Option<UVec2>,
+    spread: f32,
+    source: Source,
+}
+
+impl Default for Sdf {
+    fn default() -> Self {
+        Self {
+            size: None,
+            spread: 8.,
+            source: Source::default(),
+        }
+    }
+}
+
+impl Sdf {
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Generates a field of `width` x `height` pixels instead of the size of the image.
+    ///
+    /// Distances stay in pixels of the image, so the same spread works at any resolution.
+    #[inline]
+    #[must_use]
+    pub fn size(mut self, width: u32, height: u32) -> Self {
+        self.size = Some(UVec2::new(width, height));
+        self
+    }
+
+    /// Clamps distances to `spread` pixels on both sides of the edges, 8 by default.
+    #[inline]
+    #[must_use]
+    pub fn spread(mut self, spread: f32) -> Self {
+        self.spread = spread.max(f32::EPSILON);
+        self
+    }
+
+    /// Measures distances to `source`, the mask by default.
+    #[inline]
+    #[must_use]
+    pub fn source(mut self, source: Source) -> Self {
+        self.source = source;
+        self
+    }
+
+    /// Generates the signed distance field of an image.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use edges::{sdf::{to_luma8, Sdf, Source}, Edges};
+    /// # let image = image::open("assets/car.png").unwrap();
+    /// let edges = Edges::from(image);
+    /// let sdf = Sdf::new().size(64, 64).spread(4.).source(Source::Contours);
+    /// let texture = to_luma8(&sdf.generate(&edges), 4.);
+    /// assert_eq!(texture.dimensions(), (64, 64));
+    /// ```
+    #[must_use]
+    pub fn generate<I>(&self, edges: &Edges<I>) -> DistanceField
+    where
+        I: GenericImageView<Pixel = Bit>,
+    {
+        let source = UVec2::new(edges.width(), edges.height());
+        let size = self.size.unwrap_or(source);
+        let scale = source.as_vec2() / size.max(UVec2::ONE).as_vec2();
+        // Pixel centers of the field in pixel coordinates of the image.
+        let position = |x: u32, y: u32| (UVec2::new(x, y).as_vec2() + 0.5) * scale - 0.5;
+
+        let distance: Row = match self.source {
+            Source::Mask => {
+                let field = mask_distances(&edges.0, self.spread);
+                Box::new(move |y, row| {
+                    for (x, value) in (0..).zip(row.iter_mut()) {
+                        *value = field.sample(position(x, y));
+                    }
+                })
+            }
+            Source::Contours => {
+                let contours = Contours::new(&edges.multi_raw_with_holes(), source, self.spread);
+                Box::new(move |y, row| contours.row(y, row, &position))
+            }
+        };
+
+        let mut values = vec![0.; size.x as usize * size.y as usize];
+        if size.x > 0 {
+            #[cfg(feature = "parallel")]
+            let rows = values.par_chunks_mut(size.x as usize);
+            #[cfg(not(feature = "parallel"))]
+            let rows = values.chunks_mut(size.x as usize);
+            rows.zip(0..size.y).for_each(|(row, y)| {
+                distance(y, row);
+                for value in row {
+                    *value = value.clamp(-self.spread, self.spread);
+                }
+            });
+        }
+        ImageBuffer::from_raw(size.x, size.y, values).unwrap_or_default()
+    }
+}
+
+/// Fills the row of the field at a given height.
+type Row<'a> = Box<dyn Fn(u32, &mut [f32]) + Sync + 'a>;
+
+/// Maps distances in `-spread..=spread` to bytes, the edge at 128 and insides brighter than outsides.
+#[must_use]
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn to_luma8(field: &DistanceField, spread: f32) -> GrayImage {
+    let spread = spread.max(f32::EPSILON);
+    ImageBuffer::from_fn(field.width(), field.height(), |x, y| {
+        let distance = field.get_pixel(x, y).0[0];
+        Luma([((0.5 - distance / (2. * spread)).clamp(0., 1.) * 255.).round() as u8])
+    })
+}
+
+/// Converts a distance field to a single channel 8-bit Bevy texture, as [`to_luma8`] maps it.
+#[cfg(feature = "bevy-assets")]
+#[must_use]
+pub fn to_bevy_image(field: &DistanceField, spread: f32) -> BevyImage {
+    use bevy_asset::RenderAssetUsages;
+    use wgpu_types::{Extent3d, TextureDimension, TextureFormat};
+
+    BevyImage::new(
+        Extent3d {
+            width: field.width(),
+            height: field.height(),
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        to_luma8(field, spread).into_raw(),
+        TextureFormat::R8Unorm,
+        RenderAssetUsages::default(),
+    )
+}
+
+/// Exact distances between pixel centers of the image, padded with a transparent border.
+struct Grid {
+    width: usize,
+    height: usize,
+    values: Vec<f32>,
+}
+
+impl Grid {
+    /// Bilinear sample at a position in pixel coordinates of the image.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn sample(&self, position: Vec2) -> f32 {
+        let max = Vec2::new(self.width as f32 - 1., self.height as f32 - 1.);
+        let position = (position + 1.).clamp(Vec2::ZERO, max);
+        let low = position.floor();
+        let t = position - low;
+        let (x, y) = (low.x as usize, low.y as usize);
+        let (right, down) = ((x + 1).min(self.width - 1), (y + 1).min(self.height - 1));
+        let at = |x: usize, y: usize| self.values[y * self.width + x];
+        let top = at(x, y) + (at(right, y) - at(x, y)) * t.x;
+        let bottom = at(x, down) + (at(right, down) - at(x, down)) * t.x;
+        top + (bottom - top) * t.y
+    }
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn mask_distances<I>(image: &I, spread: f32) -> Grid
+where
+    I: GenericImageView<Pixel = Bit>,
+{
+    let (width, height) = (image.width() as usize + 2, image.height() as usize + 2);
+    let opaque: Vec<bool> = (0..height)
+        .flat_map(|y| (0..width).map(move |x| (x, y)))
+        .map(|(x, y)| {
+            (1..width - 1).contains(&x)
+                && (1..height - 1).contains(&y)
+                && *image.get_pixel(x as u32 - 1, y as u32 - 1)
+        })
+        .collect();
+    let to_opaque = squared_distances(width, height, |i| opaque[i]);
+    let to_empty = squared_distances(width, height, |i| !opaque[i]);
+    let values = opaque
+        .iter()
+        .zip(to_opaque.iter().zip(&to_empty))
+        .map(|(&inside, (&outside, &inside_distance))| {
+            // The edge lies halfway between the centers of an opaque and a transparent pixel.
+            let distance = if inside {
+                0.5 - inside_distance.sqrt()
+            } else {
+                outside.sqrt() - 0.5
+            };
+            distance.clamp(-spread - 1., spread + 1.)
+        })
+        .collect();
+    Grid {
+        width,
+        height,
+        values,
+    }
+}
+
+/// Squared distance from every pixel center to the closest seed, with the two pass
+/// transform of Felzenszwalb and Huttenlocher.
+fn squared_distances(width: usize, height: usize, seed: impl Fn(usize) -> bool) -> Vec<f32> {
+    const FAR: f32 = 1e20;
+    let mut values: Vec<f32> = (0..width * height)
+        .map(|i| if seed(i) { 0. } else { FAR })
+        .collect();
+    let mut line = Vec::new();
+    let mut scratch = Scratch::default();
+    for x in 0..width {
+        line.clear();
+        line.extend((0..height).map(|y| values[y * width + x]));
+        scratch.transform(&mut line);
+        for (y, &value) in line.iter().enumerate() {
+            values[y * width + x] = value;
+        }
+    }
+    for row in values.chunks_mut(width) {
+        scratch.transform(row);
+    }
+    values
+}
+
+/// Buffers for the one dimensional transform, the lower envelope of parabolas rooted at every sample.
+#[derive(Default)]
+struct Scratch {
+    roots: Vec<usize>,
+    bounds: Vec<f32>,
+    input: Vec<f32>,
+}
+
+impl Scratch {
+    fn transform(&mut self, values: &mut [f32]) {
+        let Self {
+            roots,
+            bounds,
+            input,
+        } = self;
+        input.clear();
+        input.extend_from_slice(values);
+        roots.clear();
+        bounds.clear();
+        let intersection = |q: usize, p: usize| {
+            let (qf, pf) = (q as f32, p as f32);
+            ((input[q] + qf * qf) - (input[p] + pf * pf)) / (2. * (qf - pf))
+        };
+
+        roots.push(0);
+        bounds.extend([f32::NEG_INFINITY, f32::INFINITY]);
+        for q in 1..input.len() {
+            let mut s = intersection(q, roots[roots.len() - 1]);
+            while roots.len() > 1 && s <= bounds[roots.len() - 1] {
+                roots.pop();
+                bounds.pop();
+                s = intersection(q, roots[roots.len() - 1]);
+            }
+            roots.push(q);
+            let last = bounds.len() - 1;
+            bounds[last] = s;
+            bounds.push(f32::INFINITY);
+        }
+
+        let mut k = 0;
+        for (q, value) in values.iter_mut().enumerate() {
+            while bounds[k + 1] < q as f32 {
+                k += 1;
+            }
+            let offset = q as f32 - roots[k] as f32;
+            *value = offset * offset + input[roots[k]];
+        }
+    }
+}
+
+/// Segments of the traced polygons, bucketed in cells as large as the spread.
+struct Contours {
+    segments: Vec<(Vec2, Vec2)>,
+    cell: f32,
+    columns: usize,
+    cells: Vec<Vec<usize>>,
+}
+
+impl Contours {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn new(objects: &[Vec<Vec<UVec2>>], size: UVec2, spread: f32) -> Self {
+        let segments: Vec<(Vec2, Vec2)> = objects
+            .iter()
+            .flatten()
+            .flat_map(|polygon| {
+                polygon
+                    .iter()
+                    .zip(polygon.iter().cycle().skip(1))
+                    .map(|(a, b)| (a.as_vec2(), b.as_vec2()))
+            })
+            .collect();
+        let cell = spread.max(1.);
+        let columns = (size.x as f32 / cell).ceil() as usize + 1;
+        let rows = (size.y as f32 / cell).ceil() as usize + 1;
+        let mut cells = vec![Vec::new(); columns * rows];
+        for (i, (a, b)) in segments.iter().enumerate() {
+            let (low, high) = ((a.min(*b) / cell).as_uvec2(), (a.max(*b) / cell).as_uvec2());
+            for y in low.y as usize..=(high.y as usize).min(rows - 1) {
+                for x in low.x as usize..=(high.x as usize).min(columns - 1) {
+                    cells[y * columns + x].push(i);
+                }
+            }
+        }
+        Self {
+            segments,
+            cell,
+            columns,
+            cells,
+        }
+    }
+
+    /// Fills a row of the field, with the even-odd rule deciding which side of the contours samples are on.
+    fn row(&self, y: u32, row: &mut [f32], position: &impl Fn(u32, u32) -> Vec2) {
+        let height = position(0, y).y;
+        let mut crossings: Vec<f32> = self
+            .segments
+            .iter()
+            .filter(|(a, b)| (a.y > height) != (b.y > height))
+            .map(|(a, b)| a.x + (height - a.y) * (b.x - a.x) / (b.y - a.y))
+            .collect();
+        crossings.sort_by(f32::total_cmp);
+
+        let mut next = 0;
+        for (x, value) in (0..).zip(row.iter_mut()) {
+            let point = position(x, y);
+            while next < crossings.len() && crossings[next] < point.x {
+                next += 1;
+            }
+            let distance = self.distance(point);
+            *value = if next % 2 == 1 { -distance } else { distance };
+        }
+    }
+
+    /// Distance to the closest segment, or infinity when none is within a cell.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn distance(&self, point: Vec2) -> f32 {
+        let cell = (point / self.cell).max(Vec2::ZERO).as_uvec2();
+        let rows = self.cells.len() / self.columns;
+        let mut closest = f32::INFINITY;
+        for y in cell.y.saturating_sub(1) as usize..=(cell.y as usize + 1).min(rows - 1) {
+            for x in cell.x.saturating_sub(1) as usize..=(cell.x as usize + 1).min(self.columns - 1)
+            {
+                for &i in &self.cells[y * self.columns + x] {
+                    let (a, b) = self.segments[i];
+                    let along = b - a;
+                    let t = ((point - a).dot(along) / along.length_squared().max(f32::EPSILON))
+                        .clamp(0., 1.);
+                    closest = closest.min(point.distance_squared(a + along * t));
+                }
+            }
+        }
+        closest.sqrt()
+    }
+}
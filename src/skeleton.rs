@@ -0,0 +1,290 @@
+use std::collections::{HashMap, HashSet};
+
+use binary_image::{BinaryImage, Bit};
+use image::{GenericImage, GenericImageView};
+
+use crate::{
+    sdf::{Sdf, Source},
+    Edges, UVec2, Vec2,
+};
+
+/// A pixel of a skeleton with the distance from its center to the closest edge of the object.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Point {
+    /// Raw pixel coordinates, like [`Edges::multi_raw`].
+    pub position: UVec2,
+    pub radius: f32,
+}
+
+/// A polyline of the skeleton between two nodes.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Branch {
+    /// Indices of the nodes at both ends, equal for loops.
+    pub start: usize,
+    pub end: usize,
+    /// The pixels from the start node to the end node, both included.
+    pub points: Vec<Point>,
+}
+
+/// The skeleton of the objects of an image, as a graph of polylines.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Skeleton {
+    /// Ends and junctions of the branches.
+    pub nodes: Vec<Point>,
+    pub branches: Vec<Branch>,
+}
+
+/// Thins the opaque pixels of an image to lines one pixel wide with Zhang-Suen thinning,
+/// keeping objects connected.
+///
+/// Objects that would vanish entirely, like 2 by 2 blocks, keep the pixel closest to their center.
+#[must_use]
+pub fn thin<I>(image: &I) -> BinaryImage
+where
+    I: GenericImageView<Pixel = Bit>,
+{
+    let (width, height) = image.dimensions();
+    let mut thinned = BinaryImage::new(width, height);
+    for (x, y, pixel) in image.pixels() {
+        thinned.put_pixel(x, y, pixel);
+    }
+
+    let mut removed = Vec::new();
+    loop {
+        let mut changed = false;
+        for step in 0..2 {
+            removed.clear();
+            for (x, y, pixel) in thinned.pixels() {
+                if !*pixel {
+                    continue;
+                }
+                let [p2, p3, p4, p5, p6, p7, p8, p9] = ring(&thinned, x, y);
+                let neighbors = [p2, p3, p4, p5, p6, p7, p8, p9];
+                let count = neighbors.iter().filter(|&&p| p).count();
+                let transitions = (0..8)
+                    .filter(|&i| !neighbors[i] && neighbors[(i + 1) % 8])
+                    .count();
+                let (first, second) = if step == 0 {
+                    (p2 && p4 && p6, p4 && p6 && p8)
+                } else {
+                    (p2 && p4 && p8, p2 && p6 && p8)
+                };
+                if (2..=6).contains(&count) && transitions == 1 && !first && !second {
+                    removed.push((x, y));
+                }
+            }
+            keep_last(&thinned, &mut removed);
+            changed |= !removed.is_empty();
+            for &(x, y) in &removed {
+                thinned.put_pixel(x, y, Bit(false));
+            }
+        }
+        if !changed {
+            return thinned;
+        }
+    }
+}
+
+/// Takes back the pixel closest to the center of every group of removed pixels
+/// that no other opaque pixel touches, so that no object is removed entirely.
+fn keep_last(image: &BinaryImage, removed: &mut Vec<(u32, u32)>) {
+    let marked: HashSet<(u32, u32)> = removed.iter().copied().collect();
+    let mut visited = HashSet::new();
+    let mut kept = HashSet::new();
+    for &start in removed.iter() {
+        if !visited.insert(start) {
+            continue;
+        }
+        let mut group = vec![start];
+        let mut alone = true;
+        let mut i = 0;
+        while let Some(&(x, y)) = group.get(i) {
+            i += 1;
+            for (dx, dy) in (-1..=1).flat_map(|dy| (-1..=1).map(move |dx| (dx, dy))) {
+                let (nx, ny) = (x.checked_add_signed(dx), y.checked_add_signed(dy));
+                if (dx, dy) == (0, 0) || !opaque(image, nx, ny) {
+                    continue;
+                }
+                let neighbor = (nx.unwrap_or_default(), ny.unwrap_or_default());
+                if !marked.contains(&neighbor) {
+                    alone = false;
+                } else if visited.insert(neighbor) {
+                    group.push(neighbor);
+                }
+            }
+        }
+        if alone {
+            let center = group
+                .iter()
+                .map(|&(x, y)| Vec2::new(x as f32, y as f32))
+                .sum::<Vec2>()
+                / group.len() as f32;
+            kept.extend(group.into_iter().min_by(|&(ax, ay), &(bx, by)| {
+                let distance =
+                    |x: u32, y: u32| Vec2::new(x as f32, y as f32).distance_squared(center);
+                distance(ax, ay).total_cmp(&distance(bx, by))
+            }));
+        }
+    }
+    removed.retain(|pixel| !kept.contains(pixel));
+}
+
+/// The 8 neighbors of a pixel clockwise from the top one, pixels outside of the image being empty.
+fn ring<I>(image: &I, x: u32, y: u32) -> [bool; 8]
+where
+    I: GenericImageView<Pixel = Bit>,
+{
+    [
+        (0, -1),
+        (1, -1),
+        (1, 0),
+        (1, 1),
+        (0, 1),
+        (-1, 1),
+        (-1, 0),
+        (-1, -1),
+    ]
+    .map(|(dx, dy)| opaque(image, x.checked_add_signed(dx), y.checked_add_signed(dy)))
+}
+
+#[inline]
+fn opaque<I>(image: &I, x: Option<u32>, y: Option<u32>) -> bool
+where
+    I: GenericImageView<Pixel = Bit>,
+{
+    x.zip(y)
+        .is_some_and(|(x, y)| image.in_bounds(x, y) && *image.get_pixel(x, y))
+}
+
+/// Neighbors of a skeleton pixel, skipping diagonal ones also reached through a side neighbor,
+/// so staircases are not mistaken for junctions.
+fn neighbors(image: &BinaryImage, position: UVec2) -> Vec<UVec2> {
+    let [top, top_right, right, bottom_right, bottom, bottom_left, left, top_left] =
+        ring(image, position.x, position.y);
+    let (x, y) = (position.x, position.y);
+    let candidates = [
+        (top, x, y.wrapping_sub(1)),
+        (right, x + 1, y),
+        (bottom, x, y + 1),
+        (left, x.wrapping_sub(1), y),
+        (top_right && !top && !right, x + 1, y.wrapping_sub(1)),
+        (bottom_right && !bottom && !right, x + 1, y + 1),
+        (bottom_left && !bottom && !left, x.wrapping_sub(1), y + 1),
+        (
+            top_left && !top && !left,
+            x.wrapping_sub(1),
+            y.wrapping_sub(1),
+        ),
+    ];
+    candidates
+        .into_iter()
+        .filter(|&(set, ..)| set)
+        .map(|(_, x, y)| UVec2::new(x, y))
+        .collect()
+}
+
+impl Skeleton {
+    /// Thins the opaque pixels of an image and follows the resulting lines into a graph.
+    ///
+    /// Nodes are the ends of lines, the pixels where lines meet and one pixel of every closed loop,
+    /// isolated pixels being nodes without branches.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use edges::{skeleton::Skeleton, Edges};
+    /// # let image = image::open("assets/terrain.png").unwrap();
+    /// let edges = Edges::from(image);
+    /// let skeleton = Skeleton::new(&edges);
+    /// let widest = skeleton
+    ///     .branches
+    ///     .iter()
+    ///     .flat_map(|branch| &branch.points)
+    ///     .max_by(|a, b| a.radius.total_cmp(&b.radius));
+    /// ```
+    #[must_use]
+    pub fn new<I>(edges: &Edges<I>) -> Self
+    where
+        I: GenericImageView<Pixel = Bit>,
+    {
+        let thinned = thin(&edges.0);
+        let spread = edges.width().max(edges.height()) as f32;
+        let field = Sdf::new()
+            .spread(spread)
+            .source(Source::Mask)
+            .generate(edges);
+        let point = |position: UVec2| Point {
+            position,
+            radius: -field.get_pixel(position.x, position.y).0[0],
+        };
+
+        let pixels: Vec<UVec2> = thinned
+            .pixels()
+            .filter(|(_, _, pixel)| **pixel)
+            .map(|(x, y, _)| UVec2::new(x, y))
+            .collect();
+        let graph: HashMap<UVec2, Vec<UVec2>> = pixels
+            .iter()
+            .map(|&p| (p, neighbors(&thinned, p)))
+            .collect();
+
+        let mut skeleton = Self::default();
+        let mut nodes = HashMap::new();
+        for &pixel in &pixels {
+            if graph[&pixel].len() != 2 {
+                nodes.insert(pixel, skeleton.nodes.len());
+                skeleton.nodes.push(point(pixel));
+            }
+        }
+
+        let mut visited = HashSet::new();
+        let follow = |skeleton: &mut Self,
+                      nodes: &HashMap<UVec2, usize>,
+                      visited: &mut HashSet<(UVec2, UVec2)>,
+                      from: UVec2| {
+            for &next in &graph[&from] {
+                if visited.contains(&(from, next)) {
+                    continue;
+                }
+                let mut line = vec![from, next];
+                visited.insert((from, next));
+                while !nodes.contains_key(&line[line.len() - 1]) {
+                    let (previous, current) = (line[line.len() - 2], line[line.len() - 1]);
+                    let Some(&after) = graph[&current].iter().find(|&&p| p != previous) else {
+                        break;
+                    };
+                    visited.insert((current, after));
+                    line.push(after);
+                }
+                let last = line[line.len() - 1];
+                visited.insert((last, line[line.len() - 2]));
+                if let Some(&end) = nodes.get(&last) {
+                    skeleton.branches.push(Branch {
+                        start: nodes[&from],
+                        end,
+                        points: line.into_iter().map(point).collect(),
+                    });
+                }
+            }
+        };
+
+        for &pixel in &pixels {
+            if nodes.contains_key(&pixel) {
+                follow(&mut skeleton, &nodes, &mut visited, pixel);
+            }
+        }
+        // Whatever is left are loops without ends or junctions.
+        for &pixel in &pixels {
+            if !nodes.contains_key(&pixel)
+                && graph[&pixel]
+                    .iter()
+                    .all(|&next| !visited.contains(&(pixel, next)))
+            {
+                nodes.insert(pixel, skeleton.nodes.len());
+                skeleton.nodes.push(point(pixel));
+                follow(&mut skeleton, &nodes, &mut visited, pixel);
+            }
+        }
+        skeleton
+    }
+}
@@ -0,0 +1,291 @@
+use std::fmt::Write;
+
+use crate::Vec2;
+
+/// A cubic Bézier curve.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct CubicBezier {
+    pub start: Vec2,
+    pub control1: Vec2,
+    pub control2: Vec2,
+    pub end: Vec2,
+}
+
+impl CubicBezier {
+    /// Evaluates the curve at `t`, from 0 at the start to 1 at the end.
+    #[inline]
+    #[must_use]
+    pub fn point(&self, t: f32) -> Vec2 {
+        let s = 1. - t;
+        self.start * (s * s * s)
+            + self.control1 * (3. * s * s * t)
+            + self.control2 * (3. * s * t * t)
+            + self.end * (t * t * t)
+    }
+
+    #[inline]
+    fn derivative(&self, t: f32) -> Vec2 {
+        let s = 1. - t;
+        (self.control1 - self.start) * (3. * s * s)
+            + (self.control2 - self.control1) * (6. * s * t)
+            + (self.end - self.control2) * (3. * t * t)
+    }
+
+    #[inline]
+    fn second_derivative(&self, t: f32) -> Vec2 {
+        (self.control2 - self.control1 * 2. + self.start) * (6. * (1. - t))
+            + (self.end - self.control2 * 2. + self.control1) * (6. * t)
+    }
+}
+
+/// Smooths a closed polygon with Chaikin's corner cutting, replacing every edge with two points at
+/// a quarter and three quarters of its length on each iteration.
+///
+/// # Returns
+///
+/// A vector of `Vec2` with `2^iterations` times as many points.
+#[must_use]
+pub fn chaikin(polygon: &[Vec2], iterations: usize) -> Vec<Vec2> {
+    let mut polygon = polygon.to_vec();
+    for _ in 0..iterations {
+        if polygon.len() < 3 {
+            break;
+        }
+        polygon = polygon
+            .iter()
+            .zip(polygon.iter().cycle().skip(1))
+            .flat_map(|(&a, &b)| [a.lerp(b, 0.25), a.lerp(b, 0.75)])
+            .collect();
+    }
+    polygon
+}
+
+/// Fits a closed polygon with cubic Bézier curves using least squares (Schneider's algorithm),
+/// splitting curves until no point of the polygon is farther than `tolerance` from them.
+///
+/// Vertices where the polygon turns by more than `corner_angle` radians are kept as sharp corners,
+/// other curves join smoothly. Pass a value of π or more to never keep corners.
+///
+/// # Example
+///
+/// ```
+/// # use edges::{smooth::{fit_curves, to_svg_path}, Edges};
+/// # let image = image::open("assets/car.png").unwrap();
+/// let edges = Edges::from(image);
+/// let curves = fit_curves(&edges.single_translated().unwrap(), 1., 2.);
+/// let path = to_svg_path(&curves);
+/// ```
+///
+/// # Returns
+///
+/// A vector of `CubicBezier` where each curve ends where the next one starts, the last ending at the first one's start.
+#[must_use]
+pub fn fit_curves(polygon: &[Vec2], tolerance: f32, corner_angle: f32) -> Vec<CubicBezier> {
+    let mut points: Vec<Vec2> = polygon.to_vec();
+    points.dedup();
+    while points.len() > 1 && points.first() == points.last() {
+        points.pop();
+    }
+    if points.len() < 3 {
+        return Vec::new();
+    }
+
+    let len = points.len();
+    let corners: Vec<usize> = (0..len)
+        .filter(|&i| {
+            let incoming = points[i] - points[(i + len - 1) % len];
+            let outgoing = points[(i + 1) % len] - points[i];
+            incoming.angle_to(outgoing).abs() > corner_angle
+        })
+        .collect();
+
+    let spacing = tolerance.max(f32::EPSILON);
+    let mut curves = Vec::new();
+    if corners.is_empty() {
+        // Starts at the vertex and tangent of a smooth join so the curve closes smoothly.
+        let dense = densify(points.iter().chain([&points[0]]).copied(), spacing);
+        let tangent = (dense[1] - dense[dense.len() - 2]).normalize_or_zero();
+        fit(&dense, tangent, -tangent, tolerance, &mut curves);
+    } else {
+        for (n, &corner) in corners.iter().enumerate() {
+            let next = corners[(n + 1) % corners.len()];
+            let count = (next + len - corner - 1) % len + 1;
+            let dense = densify((0..=count).map(|i| points[(corner + i) % len]), spacing);
+            let first = (dense[1] - dense[0]).normalize_or_zero();
+            let last = (dense[dense.len() - 2] - dense[dense.len() - 1]).normalize_or_zero();
+            fit(&dense, first, last, tolerance, &mut curves);
+        }
+    }
+    curves
+}
+
+/// Writes closed curves as SVG path commands: a move to the start followed by one cubic command per curve.
+#[must_use]
+pub fn to_svg_path(curves: &[CubicBezier]) -> String {
+    let mut path = String::new();
+    if let Some(first) = curves.first() {
+        _ = write!(path, "M {} {}", first.start.x, first.start.y);
+    }
+    for curve in curves {
+        _ = write!(
+            path,
+            " C {} {} {} {} {} {}",
+            curve.control1.x,
+            curve.control1.y,
+            curve.control2.x,
+            curve.control2.y,
+            curve.end.x,
+            curve.end.y
+        );
+    }
+    if !curves.is_empty() {
+        path.push_str(" Z");
+    }
+    path
+}
+
+/// Approximates closed curves with a polygon, sampling each of them at `segments` evenly spaced parameters.
+#[must_use]
+pub fn flatten(curves: &[CubicBezier], segments: u32) -> Vec<Vec2> {
+    let segments = segments.max(1);
+    curves
+        .iter()
+        .flat_map(|curve| (0..segments).map(move |i| curve.point(i as f32 / segments as f32)))
+        .collect()
+}
+
+/// Subdivides the edges of a polyline so no point is farther than `spacing` from the next.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn densify(points: impl Iterator<Item = Vec2>, spacing: f32) -> Vec<Vec2> {
+    let mut dense: Vec<Vec2> = Vec::new();
+    for point in points {
+        if let Some(&last) = dense.last() {
+            let steps = (last.distance(point) / spacing).ceil().max(1.) as u32;
+            dense.extend((1..=steps).map(|i| last.lerp(point, i as f32 / steps as f32)));
+        } else {
+            dense.push(point);
+        }
+    }
+    dense
+}
+
+/// Fits `points` with curves leaving the first point along `first` and arriving at the last point
+/// from the direction of `last`, both pointing into the polyline.
+fn fit(points: &[Vec2], first: Vec2, last: Vec2, tolerance: f32, curves: &mut Vec<CubicBezier>) {
+    let (start, end) = (points[0], points[points.len() - 1]);
+    if points.len() == 2 {
+        let distance = start.distance(end) / 3.;
+        curves.push(CubicBezier {
+            start,
+            control1: start + first * distance,
+            control2: end + last * distance,
+            end,
+        });
+        return;
+    }
+
+    let mut parameters = chord_lengths(points);
+    let mut curve = generate(points, &parameters, first, last);
+    let (mut error, mut split) = max_error(points, &curve, &parameters);
+    if error <= tolerance * tolerance {
+        curves.push(curve);
+        return;
+    }
+    if error <= 4. * tolerance * tolerance {
+        for _ in 0..4 {
+            reparameterize(points, &curve, &mut parameters);
+            curve = generate(points, &parameters, first, last);
+            (error, split) = max_error(points, &curve, &parameters);
+            if error <= tolerance * tolerance {
+                curves.push(curve);
+                return;
+            }
+        }
+    }
+
+    let center = (points[split - 1] - points[split + 1]).normalize_or_zero();
+    fit(&points[..=split], first, center, tolerance, curves);
+    fit(&points[split..], -center, last, tolerance, curves);
+}
+
+/// Normalized cumulative distance of every point along the polyline.
+fn chord_lengths(points: &[Vec2]) -> Vec<f32> {
+    let mut parameters = vec![0.];
+    for pair in points.windows(2) {
+        parameters.push(parameters[parameters.len() - 1] + pair[0].distance(pair[1]));
+    }
+    let total = parameters[parameters.len() - 1].max(f32::EPSILON);
+    for u in &mut parameters {
+        *u /= total;
+    }
+    parameters
+}
+
+/// Least squares fit of the control point distances along the end tangents.
+fn generate(points: &[Vec2], parameters: &[f32], first: Vec2, last: Vec2) -> CubicBezier {
+    let (start, end) = (points[0], points[points.len() - 1]);
+    let mut c = [[0f32; 2]; 2];
+    let mut x = [0f32; 2];
+    for (&point, &u) in points.iter().zip(parameters) {
+        let s = 1. - u;
+        let a1 = first * (3. * s * s * u);
+        let a2 = last * (3. * s * u * u);
+        c[0][0] += a1.dot(a1);
+        c[0][1] += a1.dot(a2);
+        c[1][1] += a2.dot(a2);
+        let rest =
+            point - (start * (s * s * s + 3. * s * s * u) + end * (3. * s * u * u + u * u * u));
+        x[0] += a1.dot(rest);
+        x[1] += a2.dot(rest);
+    }
+    c[1][0] = c[0][1];
+
+    let determinant = c[0][0] * c[1][1] - c[1][0] * c[0][1];
+    let (mut alpha1, mut alpha2) = if determinant.abs() > f32::EPSILON {
+        (
+            (x[0] * c[1][1] - x[1] * c[0][1]) / determinant,
+            (c[0][0] * x[1] - c[1][0] * x[0]) / determinant,
+        )
+    } else {
+        (0., 0.)
+    };
+
+    // Falls back to the heuristic of Wu and Barsky when the fit is degenerate.
+    let length = start.distance(end);
+    let epsilon = 1e-6 * length;
+    if alpha1 < epsilon || alpha2 < epsilon {
+        alpha1 = length / 3.;
+        alpha2 = length / 3.;
+    }
+    CubicBezier {
+        start,
+        control1: start + first * alpha1,
+        control2: end + last * alpha2,
+        end,
+    }
+}
+
+/// Squared distance of the worst fitted point, and its index.
+fn max_error(points: &[Vec2], curve: &CubicBezier, parameters: &[f32]) -> (f32, usize) {
+    let mut worst = (0., points.len() / 2);
+    for i in 1..points.len() - 1 {
+        let distance = curve.point(parameters[i]).distance_squared(points[i]);
+        if distance >= worst.0 {
+            worst = (distance, i);
+        }
+    }
+    worst
+}
+
+/// Moves every parameter one Newton-Raphson step closer to its point's projection on the curve.
+fn reparameterize(points: &[Vec2], curve: &CubicBezier, parameters: &mut [f32]) {
+    for (&point, u) in points.iter().zip(parameters.iter_mut()) {
+        let delta = curve.point(*u) - point;
+        let d1 = curve.derivative(*u);
+        let d2 = curve.second_derivative(*u);
+        let denominator = d1.dot(d1) + delta.dot(d2);
+        if denominator.abs() > f32::EPSILON {
+            *u = (*u - delta.dot(d1) / denominator).clamp(0., 1.);
+        }
+    }
+}
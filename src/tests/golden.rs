@@ -0,0 +1,163 @@
+//! Compares the edges of every image in `assets/` with the outputs saved in `assets/golden/`.
+//!
+//! Run with `EDGES_UPDATE_GOLDEN=1` to write the outputs again after an intended change.
+
+use std::{fmt::Write, fs, path::Path};
+
+use binary_image::{BinaryImage, Bit};
+use image::GenericImage;
+
+use crate::{Edges, UVec2, Vec2};
+
+fn point(json: &mut String, x: impl std::fmt::Display, y: impl std::fmt::Display) {
+    _ = write!(json, "[{x}, {y}]");
+}
+
+fn list<T>(json: &mut String, items: &[T], indent: &str, mut item: impl FnMut(&mut String, &T)) {
+    json.push('[');
+    for (i, value) in items.iter().enumerate() {
+        json.push_str(if i == 0 { "\n" } else { ",\n" });
+        json.push_str(indent);
+        item(json, value);
+    }
+    if !items.is_empty() {
+        json.push('\n');
+        json.push_str(&indent[2..]);
+    }
+    json.push(']');
+}
+
+fn polygon<T>(json: &mut String, points: &[T], write: impl Fn(&mut String, &T)) {
+    json.push('[');
+    for (i, p) in points.iter().enumerate() {
+        if i > 0 {
+            json.push_str(", ");
+        }
+        write(json, p);
+    }
+    json.push(']');
+}
+
+/// Writes the traced objects with their holes in raw coordinates and the translated outlines,
+/// one polygon per line so changes show up as readable diffs.
+fn to_json(edges: &Edges<binary_image::BinaryImage>) -> String {
+    let raw = |json: &mut String, p: &UVec2| point(json, p.x, p.y);
+    let translated = |json: &mut String, p: &Vec2| point(json, p.x, p.y);
+
+    let mut json = String::new();
+    _ = write!(
+        json,
+        "{{\n  \"width\": {},\n  \"height\": {},\n  \"objects\": ",
+        edges.width(),
+        edges.height()
+    );
+    list(
+        &mut json,
+        &edges.multi_raw_with_holes(),
+        "    ",
+        |json, object| {
+            list(json, object, "      ", |json, ring| {
+                polygon(json, ring, raw);
+            });
+        },
+    );
+    json.push_str(",\n  \"translated\": ");
+    list(
+        &mut json,
+        &edges.multi_translated(),
+        "    ",
+        |json, ring| {
+            polygon(json, ring, translated);
+        },
+    );
+    json.push_str("\n}\n");
+    json
+}
+
+#[test]
+fn assets_match_golden_outputs() {
+    let update = std::env::var_os("EDGES_UPDATE_GOLDEN").is_some();
+    let mut images: Vec<_> = fs::read_dir("assets")
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "png"))
+        .collect();
+    images.sort();
+    assert!(!images.is_empty());
+
+    for image in images {
+        let name = image.file_stem().unwrap().to_string_lossy();
+        let golden = Path::new("assets/golden").join(format!("{name}.json"));
+        let json = to_json(&Edges::from(image::open(&image).unwrap()));
+        if update {
+            fs::create_dir_all("assets/golden").unwrap();
+            fs::write(&golden, json).unwrap();
+        } else {
+            let expected = fs::read_to_string(&golden).unwrap_or_default();
+            assert!(
+                json == expected,
+                "edges of {} differ from {}, run with EDGES_UPDATE_GOLDEN=1 if the change is intended",
+                image.display(),
+                golden.display()
+            );
+        }
+    }
+}
+
+/// Masks of random noise, small enough to hit every corner case of the tracer, from a fixed seed.
+#[allow(clippy::cast_possible_truncation)]
+fn noise_masks() -> impl Iterator<Item = BinaryImage> {
+    let mut seed: u64 = 0x9E37_79B9_7F4A_7C15;
+    let mut next = move |max: u64| {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        (seed % max) as u32
+    };
+    (0..1000).map(move |_| {
+        let (width, height) = (3 + next(8), 3 + next(8));
+        let density = 30 + next(41);
+        let mut image = BinaryImage::new(width, height);
+        for y in 0..height {
+            for x in 0..width {
+                image.put_pixel(x, y, Bit(next(100) < density));
+            }
+        }
+        image
+    })
+}
+
+/// Compares the raw polygons traced from noise masks, one mask per line, with the ones saved in
+/// `assets/golden/noise.txt`.
+#[test]
+fn noise_masks_match_golden_outputs() {
+    let golden = Path::new("assets/golden/noise.txt");
+    let lines: Vec<String> = noise_masks()
+        .map(|image| {
+            let polygons: Vec<String> = Edges(image)
+                .multi_raw()
+                .iter()
+                .map(|polygon| {
+                    let points: Vec<String> =
+                        polygon.iter().map(|p| format!("{},{}", p.x, p.y)).collect();
+                    points.join(" ")
+                })
+                .collect();
+            polygons.join(" | ")
+        })
+        .collect();
+
+    if std::env::var_os("EDGES_UPDATE_GOLDEN").is_some() {
+        fs::write(golden, lines.join("\n") + "\n").unwrap();
+        return;
+    }
+    let expected = fs::read_to_string(golden).unwrap();
+    for (i, (line, expected)) in lines.iter().zip(expected.lines()).enumerate() {
+        assert!(
+            line == expected,
+            "edges of noise mask {i} differ from {}, run with EDGES_UPDATE_GOLDEN=1 if the change is intended",
+            golden.display()
+        );
+    }
+    assert_eq!(lines.len(), expected.lines().count());
+}
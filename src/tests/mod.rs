@@ -0,0 +1,1477 @@
+use crate::{Edges, Filter, UVec2, Vec2};
+use bevy_image::{prelude::Image, CompressedImageFormats, ImageSampler, ImageType};
+use bevy_render::render_asset::RenderAssetUsages;
+use std::path::Path;
+
+mod golden;
+mod roundtrip;
+
+#[test]
+fn same_image_same_edges() {
+    let dynamic_image = image::open(Path::new("assets/car.png")).unwrap();
+    let dynamic_edges = Edges::from(dynamic_image);
+
+    let bevy_image = Image::from_buffer(
+        include_bytes!("../../assets/car.png"), // buffer
+        ImageType::Extension("png"),
+        CompressedImageFormats::default(),
+        true,
+        ImageSampler::default(),
+        RenderAssetUsages::default(),
+    )
+    .unwrap();
+    let bevy_edges = Edges::try_from(bevy_image).unwrap();
+
+    assert_eq!(dynamic_edges.single_raw(), bevy_edges.single_raw());
+    assert_eq!(
+        dynamic_edges.single_translated(),
+        bevy_edges.single_translated()
+    );
+}
+
+#[test]
+fn same_images_same_edges() {
+    let dynamic_image = image::open(Path::new("assets/boulders.png")).unwrap();
+    let dynamic_edges = Edges::from(dynamic_image);
+
+    let bevy_image = Image::from_buffer(
+        include_bytes!("../../assets/boulders.png"), // buffer
+        ImageType::Extension("png"),
+        CompressedImageFormats::default(),
+        true,
+        ImageSampler::default(),
+        RenderAssetUsages::default(),
+    )
+    .unwrap();
+    let bevy_edges = Edges::try_from(bevy_image).unwrap();
+
+    assert_eq!(dynamic_edges.multi_raw(), bevy_edges.multi_raw());
+    assert_eq!(
+        dynamic_edges.multi_translated(),
+        bevy_edges.multi_translated()
+    );
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn mask(rows: &[&str]) -> binary_image::BinaryImage {
+    use image::GenericImage;
+    let mut image = binary_image::BinaryImage::new(rows[0].len() as u32, rows.len() as u32);
+    for (y, row) in rows.iter().enumerate() {
+        for (x, pixel) in row.chars().enumerate() {
+            image.put_pixel(x as u32, y as u32, binary_image::Bit(pixel == '#'));
+        }
+    }
+    image
+}
+
+#[test]
+fn filter_skips_small_objects() {
+    #[rustfmt::skip]
+    let edges = Edges(mask(&[
+        "###.....",
+        "###...##",
+        "###...##",
+        "........",
+        "#####.#.",
+        "#####...",
+    ]));
+    assert_eq!(edges.multi_raw().len(), 4);
+    assert!(edges.multi_raw().contains(&vec![UVec2::new(6, 4)]));
+
+    let large = edges
+        .filtered(Filter::new().min_area(9))
+        .collect::<Vec<_>>();
+    assert_eq!(large.len(), 2);
+    assert!(large.iter().all(|object| object.len() == 4));
+
+    let tall = edges
+        .filtered(Filter::new().min_size(1, 3))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        tall,
+        vec![vec![(2, 0), (2, 2), (0, 2), (0, 0)]
+            .into_iter()
+            .map(UVec2::from)
+            .collect::<Vec<_>>()]
+    );
+
+    let largest = edges
+        .filtered(Filter::new().max_objects(1))
+        .collect::<Vec<_>>();
+    assert_eq!(largest.len(), 1);
+    assert!(largest[0].contains(&UVec2::new(4, 5)));
+}
+
+#[test]
+fn rect_traces_only_inside() {
+    #[rustfmt::skip]
+    let edges = Edges(mask(&[
+        "##......",
+        "##..###.",
+        "....###.",
+        "....###.",
+    ]));
+    let region = edges.in_rect(3, 1, 5, 3);
+    let local = region.multi_raw();
+    assert_eq!(local.len(), 1);
+    assert!(local[0].iter().all(|p| p.x < 5 && p.y < 3));
+    assert_eq!(
+        region.multi_raw_in_parent(),
+        vec![local[0]
+            .iter()
+            .map(|p| *p + UVec2::new(3, 1))
+            .collect::<Vec<_>>()]
+    );
+    let translated = region.multi_translated();
+    assert!(translated[0]
+        .iter()
+        .all(|p| p.x.abs() <= 2.5 && p.y.abs() <= 1.5));
+    #[rustfmt::skip]
+    let whole = Edges(mask(&[
+        "........",
+        "....###.",
+        "....###.",
+        "....###.",
+    ]));
+    assert_eq!(
+        region.multi_translated_in_parent(),
+        whole.multi_translated()
+    );
+    assert_eq!(
+        region.single_translated_in_parent(),
+        whole.single_translated()
+    );
+}
+
+#[test]
+fn offset_grows_shrinks_and_splits() {
+    use crate::{
+        offset::{offset, Join},
+        utils::signed_area,
+    };
+    let square =
+        |min: Vec2, max: Vec2| vec![min, Vec2::new(max.x, min.y), max, Vec2::new(min.x, max.y)];
+    let areas = |polygons: Vec<Vec<Vec2>>| {
+        polygons
+            .iter()
+            .map(|polygon| signed_area(polygon))
+            .collect::<Vec<_>>()
+    };
+
+    let outline = square(Vec2::ZERO, Vec2::splat(10.));
+    let hole = square(Vec2::splat(3.), Vec2::splat(7.));
+    let polygons = vec![outline.clone(), hole];
+    assert_eq!(areas(offset(&polygons, 1., Join::Miter(2.))), [144., -4.]);
+    assert_eq!(areas(offset(&polygons, -1., Join::Miter(2.))), [64., -36.]);
+    assert_eq!(areas(offset(&polygons, 3., Join::Miter(2.))), [256.]);
+    assert!(offset(std::slice::from_ref(&outline), -6., Join::Round).is_empty());
+    let round = areas(offset(&[outline], 1., Join::Round));
+    assert!((round[0] - (140. + std::f32::consts::PI)).abs() < 0.1);
+
+    let apart = vec![
+        square(Vec2::ZERO, Vec2::splat(4.)),
+        square(Vec2::new(5., 0.), Vec2::new(9., 4.)),
+    ];
+    assert_eq!(areas(offset(&apart, 1., Join::Miter(2.))), [66.]);
+
+    #[rustfmt::skip]
+    let edges = Edges(mask(&[
+        "#####.....#####",
+        "#####.....#####",
+        "###############",
+        "#####.....#####",
+        "#####.....#####",
+    ]));
+    assert_eq!(edges.multi_translated().len(), 1);
+    assert_eq!(
+        areas(offset(&edges.multi_translated(), -1., Join::Miter(2.))),
+        [4., 4.]
+    );
+
+    // Pieces of boundary which do not come back to their start are not rings.
+    let points = [(0., 0.), (4., 0.), (4., 4.), (0., 4.)].map(bevy_math::DVec2::from);
+    assert!(crate::clip::link(&points, &[(0, 1), (1, 2), (2, 3)]).is_empty());
+    // The ring turns left into the diagonal, leaving the two other sides open.
+    let rings = crate::clip::link(&points, &[(0, 1), (1, 2), (2, 3), (3, 0), (1, 3)]);
+    assert_eq!(rings.len(), 1);
+    assert_eq!(rings[0].len(), 3);
+}
+
+#[test]
+fn smoothing_stays_close_to_outline() {
+    use crate::smooth::{chaikin, fit_curves, flatten, to_svg_path};
+    let edges = Edges::from(image::open(Path::new("assets/car.png")).unwrap());
+    let outline = edges.single_translated().unwrap();
+    let distance = |point: Vec2| {
+        outline
+            .iter()
+            .zip(outline.iter().cycle().skip(1))
+            .map(|(&a, &b)| {
+                let t = ((point - a).dot(b - a) / (b - a).length_squared().max(f32::EPSILON))
+                    .clamp(0., 1.);
+                point.distance(a.lerp(b, t))
+            })
+            .fold(f32::MAX, f32::min)
+    };
+
+    let smooth = chaikin(&outline, 3);
+    assert_eq!(smooth.len(), outline.len() * 8);
+    assert!(smooth.iter().all(|&p| distance(p) < 2.));
+
+    let curves = fit_curves(&outline, 1., 2.);
+    assert!(!curves.is_empty() && curves.len() < outline.len());
+    for pair in curves.windows(2) {
+        assert_eq!(pair[0].end, pair[1].start);
+    }
+    assert_eq!(curves[curves.len() - 1].end, curves[0].start);
+    assert!(flatten(&curves, 8).iter().all(|&p| distance(p) < 2.));
+    assert!(to_svg_path(&curves).starts_with('M'));
+}
+
+#[test]
+fn canonical_order_is_stable() {
+    use crate::utils::signed_area;
+    #[rustfmt::skip]
+    let before = Edges(mask(&[
+        "......###",
+        "##....###",
+        "##.......",
+        "......#..",
+        "....####.",
+    ]));
+    #[rustfmt::skip]
+    let after = Edges(mask(&[
+        "......###",
+        "##....###",
+        "##.......",
+        "......#..",
+        "...#####.",
+    ]));
+    let before = before.iter().canonical().collect::<Vec<_>>();
+    let after = after.iter().canonical().collect::<Vec<_>>();
+    assert_eq!(before.len(), 3);
+    assert_eq!(before[0], after[0]);
+    assert_eq!(before[1], after[1]);
+    assert_ne!(before[2], after[2]);
+    assert_eq!(before[0][0], UVec2::new(6, 0));
+    assert_eq!(before[1][0], UVec2::new(0, 1));
+
+    #[rustfmt::skip]
+    let edges = Edges(mask(&[
+        "###",
+        "###",
+        "#..",
+    ]));
+    for polygon in edges.anchor().translate_polygons(edges.iter().canonical()) {
+        assert!(signed_area(&polygon) > 0.);
+    }
+}
+
+#[test]
+fn holes_belong_to_the_object_around_them() {
+    // Empty pixels only form a hole through their sides, so a pixel touching the outside
+    // through a corner is still a hole, of a single point.
+    #[rustfmt::skip]
+    let corner = Edges(mask(&[
+        ".....",
+        ".###.",
+        ".#.#.",
+        ".##..",
+        ".....",
+    ]));
+    let objects = corner.multi_raw_with_holes();
+    assert_eq!(objects.len(), 1);
+    assert_eq!(objects[0][1..], [vec![UVec2::new(2, 2)]]);
+
+    // Holes reaching the border of the image are not holes.
+    #[rustfmt::skip]
+    let open = Edges(mask(&[
+        "#.#",
+        "#.#",
+        "###",
+    ]));
+    assert_eq!(open.multi_raw_with_holes()[0].len(), 1);
+
+    // Holes go around everything inside them, the objects there are traced on their own.
+    #[rustfmt::skip]
+    let nested = Edges(mask(&[
+        "#######.",
+        "#.....#.",
+        "#.###.#.",
+        "#.#.#.#.",
+        "#.###.#.",
+        "#.....#.",
+        "#######.",
+        "........",
+    ]));
+    let objects = nested.multi_raw_with_holes();
+    assert_eq!(objects.len(), 2);
+    let (outer, inner) = (&objects[0], &objects[1]);
+    assert_eq!(outer.len(), 2);
+    assert_eq!(outer[1], [(5, 1), (5, 5), (1, 5), (1, 1)].map(UVec2::from));
+    assert!(inner[0].contains(&UVec2::new(2, 2)) && inner[0].contains(&UVec2::new(4, 4)));
+    assert_eq!(inner[1..], [vec![UVec2::new(3, 3)]]);
+
+    // Objects are connected through corners, both when tracing and when filtering.
+    #[rustfmt::skip]
+    let diagonal = Edges(mask(&[
+        "##..",
+        "##..",
+        "..##",
+        "..##",
+    ]));
+    assert_eq!(diagonal.multi_raw_with_holes().len(), 1);
+    assert_eq!(diagonal.filtered(Filter::new().min_area(8)).count(), 1);
+    assert_eq!(diagonal.filtered(Filter::new().min_area(9)).count(), 0);
+}
+
+#[test]
+fn tracing_ends_on_every_mask() {
+    use std::{sync::mpsc, thread, time::Duration};
+
+    // The walk from the top right corner of this mask used to go around in a loop
+    // that never came back to where it started.
+    #[rustfmt::skip]
+    let looping = mask(&[
+        ".#.####..",
+        "#..#...#.",
+        ".#..#..#.",
+        "#.#..#.##",
+        "#.#####.#",
+        "..##.....",
+        "#.#..#...",
+        "..#......",
+    ]);
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || sender.send(Edges(looping).multi_raw()));
+    let objects = receiver
+        .recv_timeout(Duration::from_secs(10))
+        .expect("tracing did not end");
+    assert!(!objects.is_empty());
+}
+
+#[test]
+fn holes_and_hit_testing() {
+    use crate::query::{contains, point_in_polygon};
+    #[rustfmt::skip]
+    let edges = Edges(mask(&[
+        ".........",
+        ".#####...",
+        ".#...#.#.",
+        ".#.#.#.#.",
+        ".#...#.#.",
+        ".#####...",
+        ".........",
+    ]));
+    let objects = edges.multi_raw_with_holes();
+    assert_eq!(objects.len(), 3);
+    let ring = objects.iter().find(|object| object[0].len() == 4).unwrap();
+    assert_eq!(ring.len(), 2);
+    assert!(ring[1].contains(&UVec2::new(2, 2)) && ring[1].contains(&UVec2::new(4, 4)));
+
+    let square = [
+        Vec2::ZERO,
+        Vec2::new(2., 0.),
+        Vec2::new(2., 2.),
+        Vec2::new(0., 2.),
+    ];
+    assert!(point_in_polygon(Vec2::new(1., 1.), &square));
+    assert!(point_in_polygon(Vec2::new(1., 2.), &square));
+    assert!(point_in_polygon(Vec2::new(2., 2.), &square));
+    assert!(!point_in_polygon(Vec2::new(3., 2.), &square));
+    assert!(!point_in_polygon(Vec2::new(-1., 0.), &square));
+
+    // Pixel (4, 3) is in the image's center, at (0, 0) once translated.
+    let translated = edges.multi_translated_with_holes();
+    let ring = translated.iter().find(|object| object.len() == 2).unwrap();
+    assert!(!contains(Vec2::new(0., 0.), ring));
+    assert!(!contains(Vec2::new(-2., 0.), ring));
+    assert!(contains(Vec2::new(-3., 0.), ring));
+
+    let tester = edges.hit_tester();
+    let hit = tester.hit_test(Vec2::new(-3., 1.)).unwrap();
+    assert_eq!(tester.objects()[hit].len(), 2);
+    let hit = tester.hit_test(Vec2::new(3., 0.)).unwrap();
+    assert_eq!(tester.objects()[hit].len(), 1);
+    assert_eq!(tester.hit_test(Vec2::new(0., 0.)), None);
+    assert_eq!(tester.hit_test(Vec2::new(2., 0.)), None);
+    // The pixel inside the ring's hole is an object of a single point.
+    let hit = tester.hit_test(Vec2::new(-1., 0.)).unwrap();
+    assert_eq!(tester.objects()[hit], [vec![Vec2::new(-1., 0.)]]);
+}
+
+#[test]
+fn rasterize_round_trip() {
+    use crate::raster::{diff, rasterize, FillRule};
+    #[rustfmt::skip]
+    let source = mask(&[
+        "..........",
+        ".#######..",
+        ".#.....#..",
+        ".#.....##.",
+        ".#######..",
+        "...##.....",
+        "..##......",
+    ]);
+    let edges = Edges(source.clone());
+    let polygons: Vec<Vec<Vec2>> = edges
+        .multi_raw_with_holes()
+        .into_iter()
+        .flatten()
+        .map(|polygon| polygon.into_iter().map(|p| p.as_vec2()).collect())
+        .collect();
+    for rule in [FillRule::EvenOdd, FillRule::NonZero] {
+        let filled = rasterize(&polygons, edges.width(), edges.height(), rule);
+        assert!(diff(&source, &filled).is_empty());
+    }
+
+    let square = vec![
+        Vec2::new(0.5, 0.5),
+        Vec2::new(3.5, 0.5),
+        Vec2::new(3.5, 3.5),
+        Vec2::new(0.5, 3.5),
+    ];
+    let hole = vec![
+        Vec2::new(1., 1.),
+        Vec2::new(1., 3.),
+        Vec2::new(3., 3.),
+        Vec2::new(3., 1.),
+    ];
+    let outline = vec![
+        Vec2::new(0., 0.),
+        Vec2::new(4., 0.),
+        Vec2::new(4., 4.),
+        Vec2::new(0., 4.),
+    ];
+    // Centers on the outline are inside, centers on the hole are not.
+    #[rustfmt::skip]
+    let ring = mask(&[
+        "#####",
+        "#...#",
+        "#...#",
+        "#...#",
+        "#####",
+    ]);
+    for rule in [FillRule::EvenOdd, FillRule::NonZero] {
+        let filled = rasterize(&[outline.clone(), hole.clone()], 5, 5, rule);
+        assert!(diff(&filled, &ring).is_empty());
+    }
+
+    let twice = [square.clone(), square];
+    let even_odd = rasterize(&twice, 4, 4, FillRule::EvenOdd);
+    let non_zero = rasterize(&twice, 4, 4, FillRule::NonZero);
+    #[rustfmt::skip]
+    let overlap = mask(&[
+        "....",
+        ".###",
+        ".###",
+        ".###",
+    ]);
+    assert!(diff(&even_odd, &binary_image::BinaryImage::new(4, 4)).is_empty());
+    assert!(diff(&non_zero, &overlap).is_empty());
+}
+
+#[test]
+fn sdf_from_mask_and_contours() {
+    use crate::sdf::{to_luma8, Sdf, Source};
+    #[rustfmt::skip]
+    let edges = Edges(mask(&[
+        "........",
+        "........",
+        "..####..",
+        "..####..",
+        "..####..",
+        "..####..",
+        "........",
+        "........",
+    ]));
+    let field = edges.sdf(Sdf::new());
+    let at = |field: &crate::sdf::DistanceField, x, y| field.get_pixel(x, y).0[0];
+    assert!((at(&field, 3, 3) + 1.5).abs() < 1e-5);
+    assert!((at(&field, 2, 3) + 0.5).abs() < 1e-5);
+    assert!((at(&field, 1, 3) - 0.5).abs() < 1e-5);
+    assert!((at(&field, 0, 0) - (8f32.sqrt() - 0.5)).abs() < 1e-5);
+
+    let contours = edges.sdf(Sdf::new().source(Source::Contours));
+    assert!((at(&contours, 3, 3) + 1.).abs() < 1e-5);
+    assert!(at(&contours, 2, 3).abs() < 1e-5);
+    assert!((at(&contours, 1, 3) - 1.).abs() < 1e-5);
+
+    let large = edges.sdf(Sdf::new().size(16, 16).spread(2.));
+    assert_eq!(large.dimensions(), (16, 16));
+    assert!(at(&large, 7, 7) < 0.);
+    assert!((at(&large, 0, 0) - 2.).abs() < 1e-5);
+    let texture = to_luma8(&large, 2.);
+    assert_eq!(texture.get_pixel(0, 0).0[0], 0);
+    assert!(texture.get_pixel(7, 7).0[0] > 128);
+
+    #[cfg(feature = "bevy-assets")]
+    {
+        let image = crate::sdf::to_bevy_image(&large, 2.);
+        assert_eq!(image.data.as_deref(), Some(texture.as_raw().as_slice()));
+    }
+}
+
+#[test]
+fn normals_point_out_of_solids() {
+    #[rustfmt::skip]
+    let edges = Edges(mask(&[
+        ".......",
+        ".#####.",
+        ".#...#.",
+        ".#...#.",
+        ".#####.",
+        ".......",
+    ]));
+    let objects = edges.multi_translated_with_holes();
+    let normals = edges.multi_translated_normals_with_holes();
+    assert_eq!(objects.len(), 1);
+    for (polygon, normals) in objects[0].iter().zip(&normals[0]) {
+        assert_eq!(polygon.len(), normals.vertices.len());
+        assert_eq!(polygon.len(), normals.edges.len());
+    }
+    // The outline's normals point away from the center and the hole's point towards it.
+    let center = Vec2::ZERO;
+    for (point, normal) in objects[0][0].iter().zip(&normals[0][0].vertices) {
+        assert!((*point - center).dot(*normal) > 0.);
+    }
+    for (point, normal) in objects[0][1].iter().zip(&normals[0][1].vertices) {
+        assert!((*point - center).dot(*normal) < 0.);
+    }
+    for (i, normal) in normals[0][0].edges.iter().enumerate() {
+        let ring = &objects[0][0];
+        let midpoint = (ring[i] + ring[(i + 1) % ring.len()]) / 2.;
+        assert!(midpoint.dot(*normal) > 0.);
+    }
+
+    let line = [Vec2::new(0., 0.), Vec2::new(2., 0.)];
+    let normals = crate::normals::Normals::new(&line, false);
+    assert_eq!(normals.vertices, [Vec2::NEG_X, Vec2::X]);
+    assert_eq!(normals.edges[0], -normals.edges[1]);
+}
+
+#[test]
+fn occluders_merge_straight_runs() {
+    #[rustfmt::skip]
+    let edges = Edges(mask(&[
+        "........",
+        ".####...",
+        ".####...",
+        ".####...",
+        "......#.",
+        "......#.",
+        "......#.",
+    ]));
+    let segments = edges.occluders(None);
+    // Four sides of the square and a single segment for the line.
+    assert_eq!(segments.len(), 5);
+    let line = segments
+        .iter()
+        .find(|segment| segment.start.x > 0. && (segment.end - segment.start).x.abs() < 1e-6)
+        .unwrap();
+    assert_eq!((line.end - line.start).abs(), Vec2::new(0., 2.));
+
+    let top = edges.occluders(Some(Vec2::Y));
+    assert_eq!(top.len(), 1);
+    assert_eq!(top[0].normal, Vec2::Y);
+    assert_eq!((top[0].end - top[0].start).abs(), Vec2::new(3., 0.));
+
+    // The top of the first object and the bottom of the second overlap between x = 2 and x = 4.
+    let square = |min: Vec2, max: Vec2| {
+        vec![vec![
+            min,
+            Vec2::new(max.x, min.y),
+            max,
+            Vec2::new(min.x, max.y),
+        ]]
+    };
+    let adjacent = crate::occluder::segments(
+        &[
+            square(Vec2::ZERO, Vec2::new(4., 2.)),
+            square(Vec2::new(2., 2.), Vec2::new(6., 4.)),
+        ],
+        None,
+    );
+    assert_eq!(adjacent.len(), 7);
+    assert!(adjacent
+        .iter()
+        .any(|segment| (segment.end - segment.start).abs() == Vec2::new(6., 0.)));
+}
+
+#[test]
+fn navmesh_paths_go_around_walls() {
+    #[rustfmt::skip]
+    let edges = Edges(mask(&[
+        "....................",
+        ".##################.",
+        ".##################.",
+        ".##################.",
+        ".#######...########.",
+        ".#######...########.",
+        ".#######...########.",
+        ".#######...########.",
+        ".#######...########.",
+        ".#######...########.",
+        ".##################.",
+        ".##################.",
+        ".##################.",
+        ".##################.",
+        ".##################.",
+        "....................",
+    ]));
+    let navmesh = edges.navmesh(0.);
+    for (i, cell) in navmesh.cells.iter().enumerate() {
+        let points: Vec<Vec2> = cell.vertices.iter().map(|&v| navmesh.vertices[v]).collect();
+        for j in 0..points.len() {
+            let (a, b, c) = (
+                points[j],
+                points[(j + 1) % points.len()],
+                points[(j + 2) % points.len()],
+            );
+            assert!((b - a).perp_dot(c - b) >= 0., "cell {i} is not convex");
+        }
+        for (j, neighbor) in cell.neighbors.iter().enumerate() {
+            if let Some(neighbor) = *neighbor {
+                let (a, b) = (cell.vertices[j], cell.vertices[(j + 1) % points.len()]);
+                let other = &navmesh.cells[neighbor];
+                let k = other.vertices.iter().position(|&v| v == b).unwrap();
+                assert_eq!(other.vertices[(k + 1) % other.vertices.len()], a);
+                assert_eq!(other.neighbors[k], Some(i));
+            }
+        }
+    }
+    // Fewer cells than triangles, since the merged cells stay convex.
+    let triangles = crate::triangulate::triangulate(&edges.multi_translated_with_holes()[0]);
+    assert!(navmesh.cells.len() < triangles.indices.len());
+
+    // The wall in the middle is a hole, so the path goes around it.
+    let (start, goal) = (Vec2::new(-3., 0.), Vec2::new(3., 0.));
+    let path = navmesh.find_path(start, goal).unwrap();
+    assert_eq!(path.first(), Some(&start));
+    assert_eq!(path.last(), Some(&goal));
+    assert!(path.len() > 2);
+    for pair in path.windows(2) {
+        for t in 0..=10 {
+            let point = pair[0].lerp(pair[1], t as f32 / 10.);
+            assert!(navmesh.cell_at(point).is_some(), "{point} is not walkable");
+        }
+    }
+    assert_eq!(navmesh.find_path(start, Vec2::new(0., 0.)), None);
+
+    // Agents too wide for the corridor above the wall go around below it,
+    // and the widest ones find no way around.
+    let eroded = edges.navmesh(2.);
+    assert!(eroded.cell_at(Vec2::new(-7., 0.)).is_none());
+    let path = eroded
+        .find_path(Vec2::new(-5., 0.), Vec2::new(5., 0.))
+        .unwrap();
+    assert!(path.iter().any(|point| point.y < -3.));
+    let wide = edges.navmesh(3.);
+    assert!(wide
+        .find_path(Vec2::new(-5., 0.), Vec2::new(5., 0.))
+        .is_none());
+}
+
+#[test]
+fn triangles_cover_objects() {
+    use crate::{triangulate::triangulate, utils::signed_area};
+    for name in ["boulders", "car", "diagonals", "terrain"] {
+        let edges = Edges::from(image::open(format!("assets/{name}.png")).unwrap());
+        for object in edges.multi_translated_with_holes() {
+            let area = signed_area(&object[0]).abs()
+                - object[1..]
+                    .iter()
+                    .map(|hole| signed_area(hole).abs())
+                    .sum::<f32>();
+            let triangles = triangulate(&object);
+            let covered: f32 = triangles
+                .indices
+                .iter()
+                .map(|&[a, b, c]| {
+                    let (a, b, c) = (
+                        triangles.vertices[a],
+                        triangles.vertices[b],
+                        triangles.vertices[c],
+                    );
+                    let twice = (b - a).perp_dot(c - a);
+                    assert!(twice > 0., "{name}: triangle wound the wrong way");
+                    twice / 2.
+                })
+                .sum();
+            assert!(
+                (area - covered).abs() < 1e-3,
+                "{name}: {covered} instead of {area}"
+            );
+        }
+    }
+}
+
+#[test]
+fn skeleton_follows_shapes() {
+    #[rustfmt::skip]
+    let bar = Edges(mask(&[
+        ".............",
+        ".###########.",
+        ".###########.",
+        ".###########.",
+        ".............",
+    ]));
+    let skeleton = bar.skeleton();
+    assert_eq!(skeleton.nodes.len(), 2);
+    assert_eq!(skeleton.branches.len(), 1);
+    let branch = &skeleton.branches[0];
+    assert!(branch.points.iter().all(|point| point.position.y == 2));
+    assert!(branch
+        .points
+        .iter()
+        .all(|point| (point.radius - 1.5).abs() < 1e-5));
+
+    #[rustfmt::skip]
+    let plus = Edges(mask(&[
+        ".........",
+        "....#....",
+        "....#....",
+        "....#....",
+        ".#######.",
+        "....#....",
+        "....#....",
+        "....#....",
+        ".........",
+    ]));
+    let skeleton = plus.skeleton();
+    assert_eq!(skeleton.branches.len(), 4);
+    let center = skeleton
+        .nodes
+        .iter()
+        .position(|node| node.position == UVec2::new(4, 4))
+        .unwrap();
+    assert!(skeleton
+        .branches
+        .iter()
+        .all(|branch| branch.start == center || branch.end == center));
+
+    #[rustfmt::skip]
+    let ring = Edges(mask(&[
+        "........",
+        ".######.",
+        ".######.",
+        ".##..##.",
+        ".##..##.",
+        ".######.",
+        ".######.",
+        "........",
+    ]));
+    let skeleton = ring.skeleton();
+    assert_eq!(skeleton.branches.len(), 1);
+    assert_eq!(skeleton.branches[0].start, skeleton.branches[0].end);
+}
+
+#[test]
+fn skeleton_keeps_thick_strokes() {
+    use crate::skeleton::thin;
+    use image::GenericImageView;
+
+    // Plain Zhang-Suen thinning removes every pixel of a 2 by 2 block, one is kept instead.
+    #[rustfmt::skip]
+    let block = Edges(mask(&[
+        "....",
+        ".##.",
+        ".##.",
+        "....",
+    ]));
+    assert_eq!(
+        thin(&block.0)
+            .pixels()
+            .filter(|(_, _, pixel)| **pixel)
+            .count(),
+        1
+    );
+    let skeleton = block.skeleton();
+    assert_eq!(skeleton.nodes.len(), 1);
+    assert!(skeleton.branches.is_empty());
+    assert!(skeleton.nodes[0].position.cmpge(UVec2::ONE).all());
+    assert!(skeleton.nodes[0].position.cmple(UVec2::splat(2)).all());
+
+    #[rustfmt::skip]
+    let bar = Edges(mask(&[
+        "..........",
+        ".########.",
+        ".########.",
+        "..........",
+    ]));
+    let thinned: Vec<(u32, u32)> = thin(&bar.0)
+        .pixels()
+        .filter(|(_, _, pixel)| **pixel)
+        .map(|(x, y, _)| (x, y))
+        .collect();
+    assert!(thinned.len() >= 6);
+    assert!(thinned.iter().all(|&(_, y)| y == thinned[0].1));
+    let skeleton = bar.skeleton();
+    assert_eq!(skeleton.nodes.len(), 2);
+    assert_eq!(skeleton.branches.len(), 1);
+    assert_eq!(skeleton.branches[0].points.len(), thinned.len());
+
+    // Plain Zhang-Suen thinning removes this one entirely too.
+    #[rustfmt::skip]
+    let blob = Edges(mask(&[
+        "......",
+        ".####.",
+        "..###.",
+        "..###.",
+        "......",
+    ]));
+    assert_eq!(blob.skeleton().nodes.len(), 1);
+}
+
+#[test]
+fn transforms_apply_to_translated_points() {
+    use crate::transform::Transform;
+    use bevy_math::{Affine2, DVec2, Mat3};
+    use std::f32::consts::FRAC_PI_2;
+
+    let edges = Edges::from(image::open("assets/boulders.png").unwrap());
+    let translated = edges.multi_translated_with_holes();
+
+    let meters = edges.multi_transformed_with_holes(Transform::new().pixels_per_unit(8.));
+    let turned = edges.multi_transformed_with_holes(Transform::new().rotate(FRAC_PI_2));
+    let stretched = edges.multi_transformed_with_holes(
+        Transform::new().then(Affine2::from_scale(Vec2::new(2., -1.))),
+    );
+    let moved =
+        edges.multi_transformed_with_holes(Transform::new().then(Mat3::from_translation(Vec2::X)));
+    let points = |objects: &[Vec<Vec<Vec2>>]| -> Vec<Vec2> {
+        objects.iter().flatten().flatten().copied().collect()
+    };
+    for (i, p) in points(&translated).into_iter().enumerate() {
+        assert_eq!(points(&meters)[i], p / 8.);
+        assert!(points(&turned)[i].abs_diff_eq(Vec2::new(-p.y, p.x), 1e-4));
+        assert_eq!(points(&stretched)[i], Vec2::new(2. * p.x, -p.y));
+        assert_eq!(points(&moved)[i], p + Vec2::X);
+    }
+    assert_eq!(
+        edges.multi_transformed(Transform::new()),
+        edges.multi_translated()
+    );
+    assert_eq!(
+        edges.single_transformed(Transform::new().uniform_scale(2.)),
+        edges
+            .single_translated()
+            .map(|p| p.iter().map(|p| *p * 2.).collect())
+    );
+
+    // Offsets too large for f32 keep their precision in f64.
+    let offset = DVec2::new(1e9, -1e9);
+    let far = edges.multi_transformed_f64(Transform::new().translate(offset));
+    for (far, near) in far
+        .iter()
+        .flatten()
+        .zip(edges.multi_translated().iter().flatten())
+    {
+        assert_eq!(*far - offset, near.as_dvec2());
+    }
+}
+
+#[test]
+#[should_panic = "pixels_per_unit must be positive"]
+fn transforms_reject_zero_pixels_per_unit() {
+    _ = crate::transform::Transform::new().pixels_per_unit(0.);
+}
+
+#[test]
+fn tilesets_export_polygons_per_tile() {
+    use crate::tiled::Tileset;
+
+    // Two columns of 4x4 tiles with a pixel of spacing, the bottom right tile being empty.
+    #[rustfmt::skip]
+    let edges = Edges(mask(&[
+        "##.......",
+        "##.......",
+        ".......#.",
+        ".....###.",
+        ".........",
+        "...#.....",
+        "..###....",
+        "...#.....",
+        ".........",
+    ]));
+    let tileset = Tileset::new(4, 4).spacing(1).name("a \"test\"");
+    let tiles = tileset.tiles(&edges);
+    assert_eq!(
+        tiles.iter().map(|tile| tile.id).collect::<Vec<_>>(),
+        vec![0, 1, 2]
+    );
+    assert!(tiles[0].polygons[0].contains(&Vec2::new(0.5, 0.5)));
+    // The second tile starts at x = 5, after the spacing.
+    assert!(tiles[1].polygons[0].contains(&Vec2::new(2.5, 2.5)));
+    assert!(tiles[1].polygons[0].contains(&Vec2::new(0.5, 3.5)));
+
+    let tsx = tileset.to_tsx(&edges);
+    assert!(tsx.contains(r#"name="a &quot;test&quot;""#));
+    assert!(tsx.contains(r#"tilecount="4" columns="2""#));
+    assert_eq!(tsx.matches("<tile id=").count(), 3);
+    assert_eq!(tsx.matches("<polygon points=").count(), 3);
+
+    let json = tileset.to_json(&edges);
+    assert!(json.contains(r#""name":"a \"test\"""#));
+    assert_eq!(json.matches(r#""polygon":["#).count(), 3);
+    assert_eq!(json.matches('{').count(), json.matches('}').count());
+    assert_eq!(json.matches('[').count(), json.matches(']').count());
+}
+
+#[test]
+fn godot_scenes_flip_y() {
+    use crate::godot::Scene;
+
+    #[rustfmt::skip]
+    let edges = Edges(mask(&[
+        "....",
+        ".##.",
+        ".##.",
+        "....",
+    ]));
+    let tscn = Scene::new().name("Block").to_tscn(&edges);
+    assert!(tscn.starts_with("[gd_scene load_steps=2 format=3]"));
+    assert!(tscn.contains("[node name=\"Block\" type=\"Node2D\"]"));
+    assert!(tscn.contains("type=\"CollisionPolygon2D\" parent=\".\"]"));
+    assert!(tscn.contains("occluder = SubResource(\"OccluderPolygon2D_1\")"));
+    // The top left pixel is up and left of the center, so both of its coordinates are negative.
+    assert!(tscn.contains("-0.5, -0.5"));
+
+    let corner = Scene::new()
+        .centered(false)
+        .occluders(false)
+        .to_tscn(&edges);
+    assert!(corner.starts_with("[gd_scene load_steps=1 format=3]"));
+    assert!(!corner.contains("LightOccluder2D"));
+    assert!(corner.contains("1.5, 1.5"));
+
+    let tres = Scene::new().to_tres(&edges);
+    assert_eq!(tres.len(), 1);
+    assert!(tres[0].starts_with("[gd_resource type=\"OccluderPolygon2D\" format=3]"));
+}
+
+#[test]
+fn geo_polygons_are_closed_and_wound() {
+    use crate::geo::Geo;
+
+    #[rustfmt::skip]
+    let edges = Edges(mask(&[
+        "#####...",
+        "#...#...",
+        "#...#.##",
+        "#...#.##",
+        "#####...",
+    ]));
+    // North up: rows go south, so y is flipped and winding must be fixed after transforming.
+    let geo = Geo::new().gdal([100., 2., 0., 50., 0., -2.]);
+    let objects = geo.polygons(&edges);
+    assert_eq!(objects.len(), 2);
+    let signed_area = |ring: &[bevy_math::DVec2]| {
+        ring.windows(2)
+            .map(|pair| pair[0].perp_dot(pair[1]))
+            .sum::<f64>()
+            / 2.
+    };
+    let ring = &objects[0];
+    assert_eq!(ring.len(), 2);
+    assert!(ring.iter().all(|ring| ring.first() == ring.last()));
+    assert!(signed_area(&ring[0]) > 0. && signed_area(&ring[1]) < 0.);
+    // Pixels from (0, 0) to (4, 4) become 10 by 10 world units, and their 3 by 3 hole 6 by 6.
+    assert!((signed_area(&ring[0]) - 100.).abs() < 1e-9);
+    assert!((signed_area(&ring[1]) + 36.).abs() < 1e-9);
+    assert!(ring[0].contains(&bevy_math::DVec2::new(100., 50.)));
+
+    let geojson = geo.to_geojson(&edges);
+    assert!(geojson.starts_with(r#"{"type":"MultiPolygon","coordinates":[[[[1"#));
+    assert_eq!(geojson.matches('[').count(), geojson.matches(']').count());
+    let wkt = geo.to_wkt(&edges);
+    assert!(wkt.starts_with("MULTIPOLYGON (((1"));
+    assert_eq!(wkt.matches('(').count(), 1 + 2 + 3);
+
+    #[rustfmt::skip]
+    let single = Edges(mask(&[
+        "##",
+        "##",
+    ]));
+    assert_eq!(
+        Geo::new().to_wkt(&single),
+        "POLYGON ((0 0, 2 0, 2 2, 0 2, 0 0))"
+    );
+    assert!(Geo::new()
+        .to_geojson(&single)
+        .starts_with(r#"{"type":"Polygon""#));
+
+    // A hole of a single pixel and a line one pixel wide cover their pixels too.
+    #[rustfmt::skip]
+    let thin = Edges(mask(&[
+        "###....",
+        "#.#.###",
+        "###....",
+    ]));
+    assert_eq!(
+        Geo::new().to_wkt(&thin),
+        "MULTIPOLYGON (((0 0, 3 0, 3 3, 0 3, 0 0), (2 1, 1 1, 1 2, 2 2, 2 1)), \
+         ((4 1, 7 1, 7 2, 4 2, 4 1)))"
+    );
+
+    // Pixels touching through a corner are one object, whose outline touches itself there.
+    #[rustfmt::skip]
+    let diagonal = Edges(mask(&[
+        "#..",
+        ".#.",
+        "..#",
+    ]));
+    let objects = Geo::new().polygons(&diagonal);
+    assert_eq!(objects.len(), 1);
+    assert_eq!(objects[0].len(), 1);
+    assert_eq!(objects[0][0].len(), 13);
+}
+
+#[test]
+fn extrusions_are_closed() {
+    use std::collections::HashMap;
+
+    let edges = Edges::from(image::open("assets/car.png").unwrap());
+    let mesh = edges.extrude(4.);
+    assert_eq!(mesh.positions.len(), mesh.normals.len());
+    assert_eq!(mesh.positions.len(), mesh.uvs.len());
+    assert!(mesh.positions.iter().all(|p| (p.z.abs() - 2.).abs() < 1e-6));
+    assert!(mesh
+        .uvs
+        .iter()
+        .all(|uv| uv.cmpge(Vec2::ZERO).all() && uv.cmple(Vec2::ONE).all()));
+
+    // Every edge between two positions is used as many times in each direction, which is more than
+    // once only where outlines touch themselves.
+    let key = |i: u32| mesh.positions[i as usize].to_array().map(f32::to_bits);
+    let mut uses = HashMap::new();
+    for &[a, b, c] in &mesh.indices {
+        for (from, to) in [(a, b), (b, c), (c, a)] {
+            *uses.entry((key(from), key(to))).or_insert(0) += 1;
+        }
+    }
+    assert!(uses
+        .iter()
+        .all(|(&(from, to), count)| uses.get(&(to, from)) == Some(count)));
+
+    // The volume of a closed mesh is the area of the caps times the depth.
+    let volume: f32 = mesh
+        .indices
+        .iter()
+        .map(|triangle| {
+            let [a, b, c] = triangle.map(|i| mesh.positions[i as usize]);
+            a.dot(b.cross(c)) / 6.
+        })
+        .sum();
+    let area: f32 = edges
+        .multi_translated_with_holes()
+        .iter()
+        .map(|object| {
+            let triangles = crate::triangulate::triangulate(object);
+            triangles
+                .indices
+                .iter()
+                .map(|triangle| {
+                    let [a, b, c] = triangle.map(|i| triangles.vertices[i]);
+                    (b - a).perp_dot(c - a) / 2.
+                })
+                .sum::<f32>()
+        })
+        .sum();
+    assert!((volume - area * 4.).abs() < 1e-2 * volume);
+
+    let obj = mesh.to_obj();
+    assert_eq!(
+        obj.lines().filter(|line| line.starts_with("f ")).count(),
+        mesh.indices.len()
+    );
+
+    #[cfg(feature = "bevy-assets")]
+    assert_eq!(mesh.to_bevy_mesh().count_vertices(), mesh.positions.len());
+}
+
+#[cfg(feature = "animation")]
+#[test]
+fn animation_frames_are_composited() {
+    use gif::{DisposalMethod, Encoder, Frame};
+    use std::{borrow::Cow, io::Cursor, time::Duration};
+
+    // A block, a smaller frame drawn next to it and cleared afterwards, then an empty frame.
+    let mut data = Vec::new();
+    {
+        let mut encoder = Encoder::new(&mut data, 8, 8, &[0, 0, 0, 255, 255, 255]).unwrap();
+        let block: Vec<u8> = (0..64).map(|i| u8::from(i % 8 < 4 && i / 8 < 4)).collect();
+        for frame in [
+            Frame {
+                width: 8,
+                height: 8,
+                delay: 10,
+                dispose: DisposalMethod::Keep,
+                transparent: Some(0),
+                buffer: Cow::Owned(block),
+                ..Frame::default()
+            },
+            Frame {
+                width: 2,
+                height: 2,
+                left: 6,
+                top: 6,
+                delay: 5,
+                dispose: DisposalMethod::Background,
+                transparent: Some(0),
+                buffer: Cow::Owned(vec![1; 4]),
+                ..Frame::default()
+            },
+            Frame {
+                width: 1,
+                height: 1,
+                delay: 5,
+                transparent: Some(0),
+                buffer: Cow::Owned(vec![0]),
+                ..Frame::default()
+            },
+        ] {
+            encoder.write_frame(&frame).unwrap();
+        }
+    }
+
+    let frames = crate::animation::gif(Cursor::new(data)).unwrap();
+    assert_eq!(frames.len(), 3);
+    assert_eq!(frames[0].delay, Duration::from_millis(100));
+    assert_eq!(frames[1].delay, Duration::from_millis(50));
+    assert!(frames.iter().all(|frame| frame.edges.width() == 8));
+    let objects: Vec<usize> = frames
+        .iter()
+        .map(|frame| frame.edges.multi_raw().len())
+        .collect();
+    assert_eq!(objects, vec![1, 2, 1]);
+
+    let still = std::fs::read("assets/car.png").unwrap();
+    let frames = crate::animation::apng(Cursor::new(still)).unwrap();
+    assert_eq!(frames.len(), 1);
+    assert_eq!(
+        frames[0].edges.multi_raw(),
+        Edges::from(image::open("assets/car.png").unwrap()).multi_raw()
+    );
+}
+
+#[test]
+fn morph_matches_rotated_contours() {
+    use crate::morph::{correspond, interpolate};
+
+    let square = [
+        Vec2::new(0., 0.),
+        Vec2::new(4., 0.),
+        Vec2::new(4., 4.),
+        Vec2::new(0., 4.),
+    ];
+    // The same square further right, with more points, starting elsewhere and wound the other way.
+    let moved = [
+        Vec2::new(12., 4.),
+        Vec2::new(14., 4.),
+        Vec2::new(14., 0.),
+        Vec2::new(12., 0.),
+        Vec2::new(10., 0.),
+        Vec2::new(10., 4.),
+    ];
+    let (from, to) = correspond(&square, &moved, 16);
+    assert_eq!((from.len(), to.len()), (16, 16));
+    for (a, b) in from.iter().zip(&to) {
+        assert!((*b - *a).abs_diff_eq(Vec2::new(10., 0.), 1e-4));
+    }
+    let halfway = interpolate(&from, &to, 0.5);
+    assert!((halfway[0] - from[0]).abs_diff_eq(Vec2::new(5., 0.), 1e-4));
+
+    // Points are equally spaced along the perimeter.
+    assert!(from
+        .iter()
+        .zip(from.iter().cycle().skip(1))
+        .all(|(a, b)| (a.distance(*b) - 1.).abs() < 1e-4));
+    assert_eq!(correspond(&square, &[], 8), (Vec::new(), Vec::new()));
+}
+
+#[test]
+fn resampling_by_count_and_spacing() {
+    use crate::resample::Resample;
+    use std::f32::consts::FRAC_PI_4;
+
+    let rectangle = [
+        Vec2::new(0., 0.),
+        Vec2::new(6., 0.),
+        Vec2::new(6., 2.),
+        Vec2::new(0., 2.),
+    ];
+    let spacing = |points: &[Vec2]| -> Vec<f32> {
+        (0..points.len())
+            .map(|i| points[i].distance(points[(i + 1) % points.len()]))
+            .collect()
+    };
+
+    let points = Resample::count(8).apply(&rectangle);
+    assert_eq!(points.len(), 8);
+    assert!(spacing(&points).iter().all(|d| (d - 2.).abs() < 1e-5));
+
+    // 7 points spread over 16 units keep the 4 corners of the rectangle.
+    let points = Resample::count(7).keep_corners(FRAC_PI_4).apply(&rectangle);
+    assert_eq!(points.len(), 7);
+    assert!(rectangle.iter().all(|corner| points.contains(corner)));
+    // Too few points for the corners.
+    assert_eq!(
+        Resample::count(3)
+            .keep_corners(FRAC_PI_4)
+            .apply(&rectangle)
+            .len(),
+        3
+    );
+
+    let points = Resample::spacing(1.5).apply(&rectangle);
+    assert_eq!(points.len(), 11);
+    assert!(spacing(&points).iter().all(|&d| d <= 1.5 + 1e-5));
+
+    let open = Resample::count(4).closed(false).apply(&rectangle);
+    assert_eq!(open.len(), 4);
+    assert_eq!(open[0], rectangle[0]);
+    assert_eq!(open[3], rectangle[3]);
+    // Samples are 14 / 3 apart along the path, the middle ones on either side of its turns.
+    assert!(open[1].abs_diff_eq(Vec2::new(14. / 3., 0.), 1e-4));
+    assert!(open[2].abs_diff_eq(Vec2::new(14. / 3., 2.), 1e-4));
+
+    let edges = Edges::from(image::open("assets/boulders.png").unwrap());
+    let resampled = edges.multi_resampled(Resample::count(24));
+    assert_eq!(resampled.len(), edges.multi_raw().len());
+    assert!(resampled.iter().all(|points| points.len() == 24));
+    let raw = Resample::count(24).apply_raw(&edges.multi_raw()[0]);
+    assert!((raw[0] - resampled[0][0]).length() > 0.);
+    assert!(Resample::count(5).apply(&[]).is_empty());
+}
+
+#[test]
+fn moments_describe_shapes() {
+    use crate::moments::{match_shapes, Moments};
+    use std::f64::consts::FRAC_PI_2;
+
+    let square = [
+        Vec2::new(0., 0.),
+        Vec2::new(2., 0.),
+        Vec2::new(2., 2.),
+        Vec2::new(0., 2.),
+    ];
+    let moments = Moments::from_polygon(&square);
+    let reversed: Vec<Vec2> = square.iter().rev().copied().collect();
+    assert_eq!(Moments::from_polygon(&reversed), moments);
+    assert!((moments.area() - 4.).abs() < 1e-9);
+    assert!(moments
+        .centroid()
+        .unwrap()
+        .abs_diff_eq(bevy_math::DVec2::ONE, 1e-9));
+    assert!(moments.eccentricity() < 1e-6);
+    // A square of side 2 has a second central moment of 2^4 / 12 on both axes.
+    assert!((moments.mu20 - 16. / 12.).abs() < 1e-9);
+
+    #[rustfmt::skip]
+    let bar = Edges(mask(&[
+        "..........",
+        ".########.",
+        ".########.",
+        "..........",
+    ]));
+    #[rustfmt::skip]
+    let tall = Edges(mask(&[
+        "....",
+        ".##.",
+        ".##.",
+        ".##.",
+        ".##.",
+        ".##.",
+        ".##.",
+        ".##.",
+        ".##.",
+        "....",
+    ]));
+    let (wide, high) = (Moments::from_mask(&bar.0), Moments::from_mask(&tall.0));
+    assert!(wide.orientation().abs() < 1e-9);
+    assert!((high.orientation().abs() - FRAC_PI_2).abs() < 1e-9);
+    assert!(wide.eccentricity() > 0.9);
+    for (a, b) in wide.hu().into_iter().zip(high.hu()) {
+        assert!((a - b).abs() < 1e-9);
+    }
+    assert!(match_shapes(&wide, &high) < 1e-6);
+
+    // Holes are taken out of the outline.
+    let ring = Moments::from_object(&[
+        square.to_vec(),
+        vec![
+            Vec2::new(0.5, 0.5),
+            Vec2::new(1.5, 0.5),
+            Vec2::new(1.5, 1.5),
+            Vec2::new(0.5, 1.5),
+        ],
+    ]);
+    assert!((ring.area() - 3.).abs() < 1e-9);
+
+    let edges = Edges::from(image::open("assets/car.png").unwrap());
+    let car = Moments::from_object(&edges.multi_translated_with_holes()[0]);
+    assert!(match_shapes(&car, &wide) > 0.1);
+}
+
+#[test]
+fn bounds_fit_tightly() {
+    use crate::bounds::{convex_hull, enclosing_circle, oriented_bounding_box};
+    use std::f32::consts::{FRAC_PI_2, FRAC_PI_6};
+
+    // A 6 x 2 rectangle turned by 30 degrees, with points inside and along its edges.
+    let (sin, cos) = FRAC_PI_6.sin_cos();
+    let turn =
+        |x: f32, y: f32| Vec2::new(x * cos - y * sin, x * sin + y * cos) + Vec2::new(5., -3.);
+    let points = [
+        turn(-3., -1.),
+        turn(0., -0.5),
+        turn(3., -1.),
+        turn(3., 1.),
+        turn(1., 0.),
+        turn(-3., 1.),
+    ];
+    assert_eq!(convex_hull(&points).len(), 4);
+    let rectangle = oriented_bounding_box(&points).unwrap();
+    assert!((rectangle.area() - 12.).abs() < 1e-3);
+    assert!(rectangle.center.abs_diff_eq(Vec2::new(5., -3.), 1e-4));
+    // The box may be turned by any quarter turn from the rectangle.
+    let offset = (rectangle.rotation - FRAC_PI_6).rem_euclid(FRAC_PI_2);
+    assert!(offset.min(FRAC_PI_2 - offset) < 1e-4);
+    for corner in rectangle.corners() {
+        assert!(points.iter().any(|p| p.distance(corner) < 1e-3));
+    }
+
+    let circle = enclosing_circle(&points).unwrap();
+    assert!(circle.center.abs_diff_eq(Vec2::new(5., -3.), 1e-4));
+    assert!((circle.radius - 10f32.sqrt()).abs() < 1e-4);
+
+    let edges = Edges::from(image::open("assets/boulders.png").unwrap());
+    for polygon in edges.multi_translated() {
+        let rectangle = oriented_bounding_box(&polygon).unwrap();
+        let circle = enclosing_circle(&polygon).unwrap();
+        let (sin, cos) = rectangle.rotation.sin_cos();
+        let (min, max) = polygon.iter().fold(
+            (Vec2::splat(f32::MAX), Vec2::splat(f32::MIN)),
+            |(min, max), &p| (min.min(p), max.max(p)),
+        );
+        assert!(rectangle.area() <= (max - min).x * (max - min).y + 1e-3);
+        for &p in &polygon {
+            let local = p - rectangle.center;
+            let local = Vec2::new(local.x * cos + local.y * sin, local.y * cos - local.x * sin);
+            assert!(local.abs().cmple(rectangle.half_size + 1e-3).all());
+            assert!(p.distance(circle.center) <= circle.radius + 1e-3);
+        }
+    }
+    assert!(oriented_bounding_box(&[]).is_none() && enclosing_circle(&[]).is_none());
+}
+
+#[test]
+fn boolean_operations_combine_objects() {
+    use crate::{
+        boolean::{boolean, Operation},
+        utils::signed_area,
+    };
+    let square =
+        |min: Vec2, max: Vec2| vec![min, Vec2::new(max.x, min.y), max, Vec2::new(min.x, max.y)];
+    let area = |objects: &[Vec<Vec<Vec2>>]| {
+        objects
+            .iter()
+            .flatten()
+            .map(|ring| signed_area(ring))
+            .sum::<f32>()
+    };
+
+    // Holes may come in wound either way, as long as they are nested in their outline.
+    let mut hole = square(Vec2::splat(3.), Vec2::splat(7.));
+    hole.reverse();
+    let first = vec![vec![square(Vec2::ZERO, Vec2::splat(10.)), hole]];
+    let second = vec![vec![square(Vec2::splat(5.), Vec2::splat(15.))]];
+    for (operation, expected) in [
+        (Operation::Union, 163.),
+        (Operation::Intersection, 21.),
+        (Operation::Difference, 63.),
+        (Operation::Xor, 142.),
+    ] {
+        let objects = boolean(&first, &second, operation);
+        assert!((area(&objects) - expected).abs() < 1e-3, "{operation:?}");
+        for object in &objects {
+            assert!(signed_area(&object[0]) > 0.);
+            assert!(object[1..].iter().all(|hole| signed_area(hole) < 0.));
+        }
+    }
+    let union = boolean(&first, &second, Operation::Union);
+    assert_eq!(union.len(), 1);
+    assert_eq!(union[0].len(), 2);
+    // The part of the hole covered by the second square stands on its own.
+    assert_eq!(boolean(&first, &second, Operation::Xor).len(), 3);
+
+    // Overlapping objects of a single set are merged.
+    let layer = vec![
+        vec![square(Vec2::ZERO, Vec2::splat(4.))],
+        vec![square(Vec2::splat(2.), Vec2::splat(6.))],
+    ];
+    let merged = boolean(&layer, &[], Operation::Union);
+    assert_eq!(merged.len(), 1);
+    assert!((area(&merged) - 28.).abs() < 1e-3);
+
+    let edges = Edges::from(image::open("assets/boulders.png").unwrap());
+    let objects = edges.multi_translated_with_holes();
+    let union = boolean(&objects, &objects, Operation::Union);
+    assert_eq!(union.len(), objects.len());
+    // Traced outlines are wound clockwise, results always counter-clockwise.
+    assert!((area(&union) + area(&objects)).abs() < 1e-2);
+    assert!(boolean(&objects, &objects, Operation::Difference).is_empty());
+}
+
+#[cfg(feature = "rapier2d")]
+#[test]
+fn rapier_colliders_are_scaled_and_centered() {
+    use crate::collider::Strategy;
+    let edges = Edges::from(image::open("assets/boulders.png").unwrap());
+    for strategy in [
+        Strategy::Polyline,
+        Strategy::Trimesh,
+        Strategy::ConvexHull,
+        Strategy::ConvexDecomposition,
+    ] {
+        let collider = edges.rapier_collider(strategy, 16.).unwrap().build();
+        let aabb = collider.compute_aabb();
+        let half = Vec2::new(edges.width() as f32, edges.height() as f32) / 32.;
+        assert!(aabb.mins.x >= -half.x && aabb.mins.y >= -half.y);
+        assert!(aabb.maxs.x <= half.x && aabb.maxs.y <= half.y);
+        // Each boulder gets a hull, and decompositions are made of convex parts.
+        assert_eq!(
+            collider.shape().as_compound().is_some(),
+            matches!(
+                strategy,
+                Strategy::ConvexHull | Strategy::ConvexDecomposition
+            )
+        );
+    }
+    assert!(Edges(binary_image::BinaryImage::new(2, 2))
+        .rapier_collider(Strategy::Polyline, 1.)
+        .is_none());
+}
+
+#[cfg(feature = "avian2d")]
+#[test]
+fn avian_colliders_are_scaled_and_centered() {
+    use crate::collider::Strategy;
+    use avian2d::prelude::SimpleCollider;
+    let edges = Edges::from(image::open("assets/car.png").unwrap());
+    let half = Vec2::new(edges.width() as f32, edges.height() as f32) / 20.;
+    for strategy in [
+        Strategy::Polyline,
+        Strategy::Trimesh,
+        Strategy::ConvexHull,
+        Strategy::ConvexDecomposition,
+    ] {
+        let aabb = edges
+            .avian_collider(strategy, 10.)
+            .unwrap()
+            .aabb(Vec2::ZERO, 0.);
+        assert!(aabb.min.cmpge(-half).all() && aabb.max.cmple(half).all());
+        assert!(aabb.size().x > half.x);
+    }
+}
@@ -0,0 +1,244 @@
+use std::fmt::Write;
+
+use binary_image::Bit;
+use image::GenericImageView;
+
+use crate::{Edges, UVec2, Vec2};
+
+/// The collision polygons of one tile of a tileset.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Tile {
+    /// The index of the tile, counting from the top left tile row by row like Tiled.
+    pub id: u32,
+    /// The outlines of the objects of the tile, in pixels from its top left corner with y pointing down.
+    pub polygons: Vec<Vec<Vec2>>,
+}
+
+/// Exports traced outlines as polygon objects of [Tiled](https://www.mapeditor.org/) tilesets,
+/// each tile of the image being traced on its own.
+///
+/// Points are at the centers of the edge pixels, and holes are left out since Tiled polygons
+/// cannot have any.
+///
+/// # Example
+///
+/// ```
+/// # use edges::{tiled::Tileset, Edges};
+/// # let image = image::open("assets/terrain.png").unwrap();
+/// let edges = Edges::from(image);
+/// let tileset = Tileset::new(16, 16).name("terrain").image("terrain.png");
+/// let tsx = tileset.to_tsx(&edges);
+/// let json = tileset.to_json(&edges);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Tileset {
+    tile_size: UVec2,
+    spacing: u32,
+    margin: u32,
+    name: String,
+    image: String,
+}
+
+impl Tileset {
+    /// Creates a tileset of `tile_width` x `tile_height` tiles without spacing or margin.
+    #[inline]
+    #[must_use]
+    pub fn new(tile_width: u32, tile_height: u32) -> Self {
+        Self {
+            tile_size: UVec2::new(tile_width, tile_height).max(UVec2::ONE),
+            spacing: 0,
+            margin: 0,
+            name: String::from("tileset"),
+            image: String::from("tileset.png"),
+        }
+    }
+
+    /// Sets the number of pixels between two tiles.
+    #[inline]
+    #[must_use]
+    pub fn spacing(mut self, spacing: u32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the number of pixels around the tiles.
+    #[inline]
+    #[must_use]
+    pub fn margin(mut self, margin: u32) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Sets the name of the tileset, `tileset` by default.
+    #[inline]
+    #[must_use]
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    /// Sets the path of the image written in the tileset, `tileset.png` by default.
+    #[inline]
+    #[must_use]
+    pub fn image(mut self, image: impl Into<String>) -> Self {
+        self.image = image.into();
+        self
+    }
+
+    /// The number of columns and rows of tiles fitting in an image of `size`.
+    fn grid(&self, size: UVec2) -> UVec2 {
+        let stride = self.tile_size + self.spacing;
+        (size + self.spacing).saturating_sub(UVec2::splat(2 * self.margin)) / stride
+    }
+
+    /// Traces every tile of the image.
+    ///
+    /// # Returns
+    ///
+    /// A vector of `Tile` for the tiles with at least one polygon, ordered by id.
+    #[must_use]
+    pub fn tiles<I>(&self, edges: &Edges<I>) -> Vec<Tile>
+    where
+        I: GenericImageView<Pixel = Bit>,
+    {
+        let grid = self.grid(UVec2::new(edges.width(), edges.height()));
+        let stride = self.tile_size + self.spacing;
+        (0..grid.y)
+            .flat_map(|row| (0..grid.x).map(move |column| UVec2::new(column, row)))
+            .filter_map(|cell| {
+                let corner = cell * stride + self.margin;
+                let polygons: Vec<Vec<Vec2>> = edges
+                    .in_rect(corner.x, corner.y, self.tile_size.x, self.tile_size.y)
+                    .multi_raw()
+                    .into_iter()
+                    .map(|polygon| {
+                        polygon
+                            .into_iter()
+                            .map(|p| p.as_vec2() + Vec2::splat(0.5))
+                            .collect()
+                    })
+                    .collect();
+                (!polygons.is_empty()).then(|| Tile {
+                    id: cell.y * grid.x + cell.x,
+                    polygons,
+                })
+            })
+            .collect()
+    }
+
+    /// Writes the tileset in Tiled's XML format (TSX), with an object group for every tile with polygons.
+    #[must_use]
+    pub fn to_tsx<I>(&self, edges: &Edges<I>) -> String
+    where
+        I: GenericImageView<Pixel = Bit>,
+    {
+        let grid = self.grid(UVec2::new(edges.width(), edges.height()));
+        let mut xml = String::new();
+        _ = writeln!(xml, r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+        _ = writeln!(
+            xml,
+            r#"<tileset version="1.10" name="{}" tilewidth="{}" tileheight="{}" spacing="{}" margin="{}" tilecount="{}" columns="{}">"#,
+            escape_xml(&self.name),
+            self.tile_size.x,
+            self.tile_size.y,
+            self.spacing,
+            self.margin,
+            grid.x * grid.y,
+            grid.x
+        );
+        _ = writeln!(
+            xml,
+            r#" <image source="{}" width="{}" height="{}"/>"#,
+            escape_xml(&self.image),
+            edges.width(),
+            edges.height()
+        );
+        for tile in self.tiles(edges) {
+            _ = writeln!(xml, r#" <tile id="{}">"#, tile.id);
+            _ = writeln!(xml, r#"  <objectgroup draworder="index" id="2">"#);
+            for (i, polygon) in tile.polygons.iter().enumerate() {
+                let points: Vec<String> =
+                    polygon.iter().map(|p| format!("{},{}", p.x, p.y)).collect();
+                _ = writeln!(xml, r#"   <object id="{}" x="0" y="0">"#, i + 1);
+                _ = writeln!(xml, r#"    <polygon points="{}"/>"#, points.join(" "));
+                _ = writeln!(xml, "   </object>");
+            }
+            _ = writeln!(xml, "  </objectgroup>");
+            _ = writeln!(xml, " </tile>");
+        }
+        xml.push_str("</tileset>\n");
+        xml
+    }
+
+    /// Writes the tileset in Tiled's JSON format, with an object group for every tile with polygons.
+    #[must_use]
+    pub fn to_json<I>(&self, edges: &Edges<I>) -> String
+    where
+        I: GenericImageView<Pixel = Bit>,
+    {
+        let grid = self.grid(UVec2::new(edges.width(), edges.height()));
+        let mut json = String::new();
+        _ = write!(
+            json,
+            r#"{{"type":"tileset","version":"1.10","name":"{}","image":"{}","imagewidth":{},"imageheight":{},"tilewidth":{},"tileheight":{},"spacing":{},"margin":{},"tilecount":{},"columns":{},"tiles":["#,
+            escape_json(&self.name),
+            escape_json(&self.image),
+            edges.width(),
+            edges.height(),
+            self.tile_size.x,
+            self.tile_size.y,
+            self.spacing,
+            self.margin,
+            grid.x * grid.y,
+            grid.x
+        );
+        for (i, tile) in self.tiles(edges).iter().enumerate() {
+            if i > 0 {
+                json.push(',');
+            }
+            _ = write!(
+                json,
+                r#"{{"id":{},"objectgroup":{{"type":"objectgroup","id":2,"name":"","draworder":"index","x":0,"y":0,"opacity":1,"visible":true,"objects":["#,
+                tile.id
+            );
+            for (j, polygon) in tile.polygons.iter().enumerate() {
+                if j > 0 {
+                    json.push(',');
+                }
+                let points: Vec<String> = polygon
+                    .iter()
+                    .map(|p| format!(r#"{{"x":{},"y":{}}}"#, p.x, p.y))
+                    .collect();
+                _ = write!(
+                    json,
+                    r#"{{"id":{},"name":"","type":"","x":0,"y":0,"width":0,"height":0,"rotation":0,"visible":true,"polygon":[{}]}}"#,
+                    j + 1,
+                    points.join(",")
+                );
+            }
+            json.push_str("]}}");
+        }
+        json.push_str("]}\n");
+        json
+    }
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn escape_json(text: &str) -> String {
+    let mut escaped = String::new();
+    for c in text.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            c if c.is_control() => _ = write!(escaped, "\\u{:04x}", c as u32),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
@@ -0,0 +1,146 @@
+use crate::{Affine2, DAffine2, DVec2, Mat3, Vec2};
+
+/// Steps applied to translated points, e.g. to go from pixels to meters or to place objects in a world.
+///
+/// Steps are applied in the order they are added, in double precision, so points can be
+/// returned as `DVec2` without losing the precision of large translations.
+/// Mirroring steps (a negative scale on one axis) reverse the winding of polygons.
+///
+/// # Example
+///
+/// ```
+/// # use edges::{transform::Transform, Edges};
+/// # let image = image::open("assets/car.png").unwrap();
+/// let edges = Edges::from(image);
+/// let transform = Transform::new()
+///     .pixels_per_unit(32.)
+///     .rotate(std::f32::consts::FRAC_PI_4)
+///     .translate([1e9, -1e9].into());
+/// let car = edges.single_transformed_f64(transform).unwrap();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform(DAffine2);
+
+impl Default for Transform {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Transform {
+    /// The transform leaving points where they are.
+    pub const IDENTITY: Self = Self(DAffine2::IDENTITY);
+
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::IDENTITY
+    }
+
+    /// Scales points by `scale` on each axis.
+    #[inline]
+    #[must_use]
+    pub fn scale(self, scale: Vec2) -> Self {
+        self.then(DAffine2::from_scale(scale.as_dvec2()))
+    }
+
+    /// Scales points by `scale` on both axes.
+    #[inline]
+    #[must_use]
+    pub fn uniform_scale(self, scale: f32) -> Self {
+        self.scale(Vec2::splat(scale))
+    }
+
+    /// Divides points by `pixels_per_unit`, e.g. the number of pixels in a meter.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pixels_per_unit` is not a positive finite number.
+    #[inline]
+    #[must_use]
+    pub fn pixels_per_unit(self, pixels_per_unit: f32) -> Self {
+        assert!(
+            pixels_per_unit.is_finite() && pixels_per_unit > 0.,
+            "pixels_per_unit must be positive"
+        );
+        self.then(DAffine2::from_scale(DVec2::splat(
+            1. / f64::from(pixels_per_unit),
+        )))
+    }
+
+    /// Rotates points counter-clockwise by `angle` radians around the origin.
+    #[inline]
+    #[must_use]
+    pub fn rotate(self, angle: f32) -> Self {
+        self.then(DAffine2::from_angle(f64::from(angle)))
+    }
+
+    /// Moves points by `translation`.
+    #[inline]
+    #[must_use]
+    pub fn translate(self, translation: DVec2) -> Self {
+        self.then(DAffine2::from_translation(translation))
+    }
+
+    /// Applies an arbitrary affine transform after the previous steps.
+    ///
+    /// A `Mat3` is read as a 2D homogeneous matrix, its last row is ignored.
+    #[inline]
+    #[must_use]
+    pub fn then(self, affine: impl Into<Transform>) -> Self {
+        Self(affine.into().0 * self.0)
+    }
+
+    /// The transform as a double precision affine transform.
+    #[inline]
+    #[must_use]
+    pub fn affine(self) -> DAffine2 {
+        self.0
+    }
+
+    /// Transforms a polygon.
+    ///
+    /// # Returns
+    ///
+    /// A vector of `Vec2` representing the transformed points.
+    #[inline]
+    #[must_use]
+    pub fn apply(self, polygon: &[Vec2]) -> Vec<Vec2> {
+        polygon
+            .iter()
+            .map(|p| self.0.transform_point2(p.as_dvec2()).as_vec2())
+            .collect()
+    }
+
+    /// Transforms a polygon in double precision.
+    ///
+    /// # Returns
+    ///
+    /// A vector of `DVec2` representing the transformed points.
+    #[inline]
+    #[must_use]
+    pub fn apply_f64(self, polygon: &[Vec2]) -> Vec<DVec2> {
+        polygon
+            .iter()
+            .map(|p| self.0.transform_point2(p.as_dvec2()))
+            .collect()
+    }
+}
+
+impl From<DAffine2> for Transform {
+    fn from(affine: DAffine2) -> Self {
+        Self(affine)
+    }
+}
+
+impl From<Affine2> for Transform {
+    fn from(affine: Affine2) -> Self {
+        Self::from(Mat3::from(affine))
+    }
+}
+
+impl From<Mat3> for Transform {
+    fn from(matrix: Mat3) -> Self {
+        Self(DAffine2::from_mat3(matrix.as_dmat3()))
+    }
+}
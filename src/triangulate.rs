@@ -0,0 +1,328 @@
+use std::collections::HashMap;
+
+use crate::{
+    clip,
+    utils::{group, orient, signed_area},
+    Vec2,
+};
+
+/// Triangles covering a polygon, sharing their vertices.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Triangles {
+    pub vertices: Vec<Vec2>,
+    /// Indices into `vertices`, wound like outlines with a positive signed area.
+    pub indices: Vec<[usize; 3]>,
+}
+
+/// Triangulates an object made of an outline followed by its holes,
+/// as returned by [`Edges::multi_translated_with_holes`](crate::Edges::multi_translated_with_holes).
+///
+/// Every edge of the polygons is an edge of the triangles, so the triangulation can be used
+/// to navigate or extrude the object. Holes are joined to the outline by bridges before ears
+/// are clipped from the resulting polygon.
+/// Parts without area, like lines one pixel wide, are left out.
+///
+/// # Example
+///
+/// ```
+/// # use edges::{triangulate::triangulate, Edges};
+/// # let image = image::open("assets/car.png").unwrap();
+/// let edges = Edges::from(image);
+/// let car = &edges.multi_translated_with_holes()[0];
+/// let triangles = triangulate(car);
+/// assert!(!triangles.indices.is_empty());
+/// ```
+#[must_use]
+pub fn triangulate(object: &[Vec<Vec2>]) -> Triangles {
+    // Resolving splits outlines touching themselves, so only simple polygons are left to clip.
+    let rings = clip::resolve(&[&clip::to_f64(&orient(object.to_vec()))], |windings| {
+        windings[0] > 0
+    });
+    let mut list = List::default();
+    let mut lookup = HashMap::new();
+    let mut indices = Vec::new();
+    for part in group(rings) {
+        let rings: Vec<Vec<usize>> = part
+            .iter()
+            .enumerate()
+            .map(|(i, polygon)| {
+                clean(polygon, i > 0)
+                    .into_iter()
+                    .map(|p| {
+                        *lookup
+                            .entry(p.to_array().map(f32::to_bits))
+                            .or_insert_with(|| {
+                                list.vertices.push(p);
+                                list.vertices.len() - 1
+                            })
+                    })
+                    .collect()
+            })
+            .collect();
+        let Some((outline, holes)) = rings
+            .split_first()
+            .filter(|(outline, _)| outline.len() >= 3)
+        else {
+            continue;
+        };
+        let start = list.ring(outline);
+        let holes: Vec<usize> = holes
+            .iter()
+            .filter(|hole| hole.len() >= 3)
+            .map(|hole| list.ring(hole))
+            .collect();
+        list.bridge(start, holes);
+        list.clip(start, &mut indices);
+    }
+    Triangles {
+        vertices: list.vertices,
+        indices,
+    }
+}
+
+/// Removes repeated points and the middle of straight or folded corners, then winds the polygon
+/// with a positive signed area for outlines and a negative one for holes.
+fn clean(polygon: &[Vec2], hole: bool) -> Vec<Vec2> {
+    let mut ring: Vec<Vec2> = polygon.to_vec();
+    ring.dedup();
+    while ring.len() > 1 && ring.first() == ring.last() {
+        ring.pop();
+    }
+    let mut changed = true;
+    while changed && ring.len() >= 3 {
+        changed = false;
+        let mut i = 0;
+        while i < ring.len() && ring.len() >= 3 {
+            let len = ring.len();
+            let (previous, point, next) = (ring[(i + len - 1) % len], ring[i], ring[(i + 1) % len]);
+            if previous == next || cross(previous, point, next) == 0. {
+                ring.remove(i);
+                changed = true;
+            } else {
+                i += 1;
+            }
+        }
+    }
+    let area = signed_area(&ring);
+    if ring.len() < 3 || area == 0. {
+        return Vec::new();
+    }
+    if (area < 0.) != hole {
+        ring.reverse();
+    }
+    ring
+}
+
+/// Twice the signed area of the triangle `abc`.
+#[inline]
+fn cross(a: Vec2, b: Vec2, c: Vec2) -> f32 {
+    (b - a).perp_dot(c - b)
+}
+
+/// Whether `p` is inside the triangle `abc` wound with a positive area, or on its boundary.
+#[inline]
+fn in_triangle(p: Vec2, a: Vec2, b: Vec2, c: Vec2) -> bool {
+    (b - a).perp_dot(p - a) >= 0. && (c - b).perp_dot(p - b) >= 0. && (a - c).perp_dot(p - c) >= 0.
+}
+
+/// Whether segments `ab` and `cd` have a point in common.
+fn intersect(a: Vec2, b: Vec2, c: Vec2, d: Vec2) -> bool {
+    let side = |p: Vec2, q: Vec2, r: Vec2| (q - p).perp_dot(r - p);
+    let within = |p: Vec2, q: Vec2, r: Vec2| r.cmpge(p.min(q)).all() && r.cmple(p.max(q)).all();
+    let (d1, d2, d3, d4) = (side(c, d, a), side(c, d, b), side(a, b, c), side(a, b, d));
+    (d1 * d2 < 0. && d3 * d4 < 0.)
+        || (d1 == 0. && within(c, d, a))
+        || (d2 == 0. && within(c, d, b))
+        || (d3 == 0. && within(a, b, c))
+        || (d4 == 0. && within(a, b, d))
+}
+
+/// A circular doubly linked list of polygon corners, some corners sharing a vertex once holes are bridged.
+#[derive(Default)]
+struct List {
+    vertices: Vec<Vec2>,
+    corners: Vec<usize>,
+    previous: Vec<usize>,
+    next: Vec<usize>,
+}
+
+impl List {
+    #[inline]
+    fn point(&self, corner: usize) -> Vec2 {
+        self.vertices[self.corners[corner]]
+    }
+
+    fn push(&mut self, vertex: usize) -> usize {
+        self.corners.push(vertex);
+        self.previous.push(self.corners.len() - 1);
+        self.next.push(self.corners.len() - 1);
+        self.corners.len() - 1
+    }
+
+    /// Links a ring of vertices, returning its first corner.
+    fn ring(&mut self, vertices: &[usize]) -> usize {
+        let first = self.corners.len();
+        for (i, &vertex) in vertices.iter().enumerate() {
+            let corner = self.push(vertex);
+            self.previous[corner] = first + (i + vertices.len() - 1) % vertices.len();
+            self.next[corner] = first + (i + 1) % vertices.len();
+        }
+        first
+    }
+
+    /// The corners of the ring going through `start`, starting there.
+    fn cycle(&self, start: usize) -> impl Iterator<Item = usize> + '_ {
+        std::iter::once(start).chain(
+            std::iter::successors(Some(self.next[start]), move |&corner| {
+                Some(self.next[corner])
+            })
+            .take_while(move |&corner| corner != start),
+        )
+    }
+
+    fn unlink(&mut self, corner: usize) {
+        let (previous, next) = (self.previous[corner], self.next[corner]);
+        self.next[previous] = next;
+        self.previous[next] = previous;
+    }
+
+    /// Whether the direction from `corner` to `target` points into the polygon, on the left of its edges.
+    fn locally_inside(&self, corner: usize, target: Vec2) -> bool {
+        let point = self.point(corner);
+        let (previous, next) = (
+            self.point(self.previous[corner]) - point,
+            self.point(self.next[corner]) - point,
+        );
+        let direction = target - point;
+        if next.perp_dot(previous) > 0. {
+            next.perp_dot(direction) > 0. && direction.perp_dot(previous) > 0.
+        } else {
+            !(previous.perp_dot(direction) >= 0. && direction.perp_dot(next) >= 0.)
+        }
+    }
+
+    /// Joins every hole to the outline with a pair of edges going to and back from it,
+    /// starting with the leftmost holes.
+    fn bridge(&mut self, start: usize, holes: Vec<usize>) {
+        let mut holes: Vec<usize> = holes
+            .into_iter()
+            .map(|hole| {
+                self.cycle(hole)
+                    .min_by(|&a, &b| {
+                        let (a, b) = (self.point(a), self.point(b));
+                        a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y))
+                    })
+                    .unwrap_or(hole)
+            })
+            .collect();
+        holes.sort_by(|&a, &b| self.point(a).x.total_cmp(&self.point(b).x));
+
+        for (i, &hole) in holes.iter().enumerate() {
+            let point = self.point(hole);
+            let mut candidates: Vec<usize> = self.cycle(start).collect();
+            candidates.sort_by(|&a, &b| {
+                self.point(a)
+                    .distance_squared(point)
+                    .total_cmp(&self.point(b).distance_squared(point))
+            });
+            let visible = candidates.into_iter().find(|&corner| {
+                let target = self.point(corner);
+                self.locally_inside(corner, point)
+                    && self.locally_inside(hole, target)
+                    && [start, hole]
+                        .into_iter()
+                        .chain(holes[i + 1..].iter().copied())
+                        .all(|ring| self.clear(ring, target, point))
+            });
+            if let Some(corner) = visible {
+                self.splice(corner, hole);
+            }
+        }
+    }
+
+    /// Whether the segment `ab` crosses no edge of a ring, apart from edges ending at `a` or `b`.
+    fn clear(&self, ring: usize, a: Vec2, b: Vec2) -> bool {
+        self.cycle(ring).all(|corner| {
+            let (c, d) = (self.point(corner), self.point(self.next[corner]));
+            [c, d].iter().any(|&p| p == a || p == b) || !intersect(a, b, c, d)
+        })
+    }
+
+    /// Links `outer` to `hole` and back, duplicating both corners.
+    fn splice(&mut self, outer: usize, hole: usize) {
+        let outer_copy = self.push(self.corners[outer]);
+        let hole_copy = self.push(self.corners[hole]);
+        let (outer_next, hole_previous) = (self.next[outer], self.previous[hole]);
+
+        self.next[outer] = hole;
+        self.previous[hole] = outer;
+        self.next[hole_previous] = hole_copy;
+        self.previous[hole_copy] = hole_previous;
+        self.next[hole_copy] = outer_copy;
+        self.previous[outer_copy] = hole_copy;
+        self.next[outer_copy] = outer_next;
+        self.previous[outer_next] = outer_copy;
+    }
+
+    fn is_ear(&self, corner: usize) -> bool {
+        let (a, b, c) = (
+            self.point(self.previous[corner]),
+            self.point(corner),
+            self.point(self.next[corner]),
+        );
+        if cross(a, b, c) <= 0. {
+            return false;
+        }
+        let mut other = self.next[self.next[corner]];
+        while other != self.previous[corner] {
+            let p = self.point(other);
+            // Only reflex corners can block an ear, some of them are inside whenever any corner is.
+            if p != a
+                && p != b
+                && p != c
+                && in_triangle(p, a, b, c)
+                && cross(
+                    self.point(self.previous[other]),
+                    p,
+                    self.point(self.next[other]),
+                ) <= 0.
+            {
+                return false;
+            }
+            other = self.next[other];
+        }
+        true
+    }
+
+    /// Clips ears until no triangle remains.
+    fn clip(&mut self, start: usize, indices: &mut Vec<[usize; 3]>) {
+        let mut corner = start;
+        let mut remaining = self.cycle(start).count();
+        let mut stalled = 0;
+        while remaining > 2 {
+            let (previous, next) = (self.previous[corner], self.next[corner]);
+            let area = cross(self.point(previous), self.point(corner), self.point(next));
+            // Straight corners are dropped, and once no ear is left the polygon is not simple
+            // so convex corners and then any corner are clipped anyway.
+            if area == 0.
+                || self.is_ear(corner)
+                || (stalled > remaining && area > 0.)
+                || stalled > 2 * remaining
+            {
+                if area > 0. {
+                    indices.push([
+                        self.corners[previous],
+                        self.corners[corner],
+                        self.corners[next],
+                    ]);
+                }
+                self.unlink(corner);
+                remaining -= 1;
+                stalled = 0;
+            } else {
+                stalled += 1;
+            }
+            corner = next;
+        }
+    }
+}
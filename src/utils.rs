@@ -17,3 +17,126 @@ pub fn center_of(polygon: &[UVec2]) -> Option<Vec2> {
         .reduce(|acc, p| acc + p)
         .map(|sum| (sum / polygon.len() as u32).as_vec2())
 }
+
+/// Computes the signed area of a polygon with the shoelace formula.
+///
+/// # Returns
+///
+/// A positive area for counter-clockwise polygons (when y points up) and a negative one for clockwise polygons.
+#[inline]
+#[must_use]
+pub fn signed_area(polygon: &[Vec2]) -> f32 {
+    polygon
+        .iter()
+        .zip(polygon.iter().cycle().skip(1))
+        .map(|(a, b)| a.perp_dot(*b))
+        .sum::<f32>()
+        / 2.
+}
+
+/// Orients polygons by nesting: polygons inside an even number of others are outlines and become
+/// counter-clockwise (when y points up), the others are holes and become clockwise.
+///
+/// # Returns
+///
+/// The polygons in the same order, reversed where needed.
+#[must_use]
+pub fn orient(mut polygons: Vec<Vec<Vec2>>) -> Vec<Vec<Vec2>> {
+    let holes = holes(&polygons);
+    for (polygon, hole) in polygons.iter_mut().zip(holes) {
+        if (signed_area(polygon) < 0.) != hole {
+            polygon.reverse();
+        }
+    }
+    polygons
+}
+
+/// Finds which polygons are holes, i.e. nested inside an odd number of other polygons.
+pub(crate) fn holes(polygons: &[Vec<Vec2>]) -> Vec<bool> {
+    (0..polygons.len())
+        .map(|i| {
+            polygons[i].first().is_some_and(|&point| {
+                polygons
+                    .iter()
+                    .enumerate()
+                    .filter(|&(j, other)| j != i && contains(other, point))
+                    .count()
+                    % 2
+                    == 1
+            })
+        })
+        .collect()
+}
+
+/// Groups rings wound with the inside on their left, as resolved by the clipping engine,
+/// into objects made of an outline followed by the holes directly inside it.
+pub(crate) fn group(rings: Vec<Vec<Vec2>>) -> Vec<Vec<Vec<Vec2>>> {
+    let (outlines, holes): (Vec<_>, Vec<_>) =
+        rings.into_iter().partition(|ring| signed_area(ring) > 0.);
+    let mut objects: Vec<Vec<Vec<Vec2>>> = outlines.into_iter().map(|ring| vec![ring]).collect();
+    for hole in holes {
+        // Holes may touch their outline, so the first point strictly inside one decides.
+        let owner = hole.iter().find_map(|&point| {
+            (0..objects.len())
+                .filter(|&i| contains(&objects[i][0], point))
+                .min_by(|&a, &b| {
+                    signed_area(&objects[a][0]).total_cmp(&signed_area(&objects[b][0]))
+                })
+        });
+        if let Some(owner) = owner {
+            objects[owner].push(hole);
+        }
+    }
+    objects
+}
+
+/// Even-odd test of a point against a polygon, points on the boundary are not inside.
+fn contains(polygon: &[Vec2], point: Vec2) -> bool {
+    polygon
+        .iter()
+        .zip(polygon.iter().cycle().skip(1))
+        .filter(|(a, b)| {
+            (a.y > point.y) != (b.y > point.y)
+                && point.x < a.x + (point.y - a.y) * (b.x - a.x) / (b.y - a.y)
+        })
+        .count()
+        % 2
+        == 1
+}
+
+/// Puts a raw polygon in a canonical form: repeated points removed, starting at its smallest point
+/// (by x, then y) and wound counter-clockwise once translated, where y points up.
+/// Polygons without area continue towards the smaller of the start's two neighbors.
+///
+/// Polygons covering the same pixels always have the same canonical form,
+/// regardless of where tracing started or which way it went around them.
+///
+/// # Returns
+///
+/// A vector of `UVec2` with the points of the polygon in canonical order.
+#[must_use]
+pub fn canonical(mut polygon: Vec<UVec2>) -> Vec<UVec2> {
+    polygon.dedup();
+    while polygon.len() > 1 && polygon.first() == polygon.last() {
+        polygon.pop();
+    }
+    let Some(start) = (0..polygon.len()).min_by_key(|&i| polygon[i].to_array()) else {
+        return polygon;
+    };
+    polygon.rotate_left(start);
+
+    // Raw points have y pointing down, so counter-clockwise once translated is a negative area here.
+    let area: i64 = polygon
+        .iter()
+        .zip(polygon.iter().cycle().skip(1))
+        .map(|(a, b)| i64::from(a.x) * i64::from(b.y) - i64::from(b.x) * i64::from(a.y))
+        .sum();
+    let reverse = match area {
+        0 => polygon.len() > 2 && polygon[polygon.len() - 1].to_array() < polygon[1].to_array(),
+        area => area > 0,
+    };
+    if reverse {
+        polygon[1..].reverse();
+    }
+    polygon
+}